@@ -0,0 +1,89 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Registers every day found in `src/yearYYYY/dayNN/mod.rs`, so adding a day (see `aoc04 new`) does
+// not require touching `main.rs`. Generates `$OUT_DIR/days.rs` with one module per year holding its
+// days' modules and a `register_days` function adding the days' solutions to a `Puzzler`, which
+// `main.rs` includes.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src_dir = manifest_dir.join("src");
+
+    println!("cargo:rerun-if-changed=src");
+
+    let days = find_days(&src_dir);
+    let mut code = String::new();
+    let mut open_year: Option<usize> = None;
+
+    for (year, day, path) in &days {
+        if open_year != Some(*year) {
+            if open_year.is_some() {
+                code.push_str("}\n\n");
+            }
+
+            code.push_str(&format!("mod year{} {{\n", year));
+            open_year = Some(*year);
+        }
+
+        code.push_str(&format!("    #[path = {:?}]\n    pub mod day{:0>2};\n", path.display().to_string(), day));
+    }
+
+    if open_year.is_some() {
+        code.push_str("}\n");
+    }
+
+    code.push_str("\nfn register_days(puzzler: &mut puzzle::Puzzler) {\n");
+
+    for (year, day, _) in &days {
+        code.push_str(&format!("    puzzler.add_solution::<year{0}::day{1:0>2}::Day{1:0>2}>({0}, {1});\n", year, day));
+    }
+
+    code.push_str("}\n");
+
+    let out_file = PathBuf::from(env::var("OUT_DIR").unwrap()).join("days.rs");
+
+    fs::write(&out_file, code).unwrap();
+}
+
+// The days with a module, ordered by year and day
+fn find_days(src_dir: &Path) -> Vec<(usize, usize, PathBuf)> {
+    let mut days = Vec::<(usize, usize, PathBuf)>::new();
+
+    for year_entry in fs::read_dir(src_dir).unwrap() {
+        let year_dir = year_entry.unwrap().path();
+
+        let Some(year) = number_after_prefix(&year_dir, "year", 4) else {
+            continue;
+        };
+
+        for entry in fs::read_dir(&year_dir).unwrap() {
+            let day_dir = entry.unwrap().path();
+            let module = day_dir.join("mod.rs");
+
+            if let Some(day) = number_after_prefix(&day_dir, "day", 2) {
+                if module.is_file() {
+                    days.push((year, day, module));
+                }
+            }
+        }
+    }
+
+    days.sort();
+
+    days
+}
+
+// The number in a directory name like `day07`, if it is one
+fn number_after_prefix(path: &Path, prefix: &str, digits: usize) -> Option<usize> {
+    if !path.is_dir() {
+        return None;
+    }
+
+    let name = path.file_name()?.to_string_lossy().to_string();
+
+    match name.strip_prefix(prefix) {
+        Some(n) if n.len() == digits => n.parse::<usize>().ok(),
+        _ => None,
+    }
+}
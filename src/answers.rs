@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::puzzle::{Answer, Part};
+
+pub const ANSWERS_FILE: &str = "answers";
+
+// The known good answers of a day, stored in a file named `answers` next to the day's inputs.
+// Each line maps an input file and a part to the answer, e.g.:
+//
+//   input_test.part1 = 24000
+//   input_full.part2 = RGLVRCQSB
+//
+// Line breaks in (multi-line) answers are escaped as `\n`. Empty lines and lines starting with `#`
+// are ignored.
+pub struct AnswerRegistry {
+    path: PathBuf,
+    entries: BTreeMap<(String, Part), String>,
+}
+
+impl AnswerRegistry {
+    // Loads the registry from the given day's directory, a missing file yields an empty registry
+    pub fn load(day_dir: &Path) -> Result<AnswerRegistry, String> {
+        let path = day_dir.join(ANSWERS_FILE);
+        let mut entries = BTreeMap::<(String, Part), String>::new();
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("Failed to read '{}': {}", path.display(), err)),
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid_line = || format!("Invalid entry in '{}', line {}: '{}'", path.display(), i + 1, line);
+
+            let (key, value) = line.split_once('=').ok_or_else(invalid_line)?;
+            let (input, part) = key.trim().rsplit_once('.').ok_or_else(invalid_line)?;
+
+            let part = Part::from_key(part).ok_or_else(invalid_line)?;
+
+            entries.insert((input.to_string(), part), value.trim().to_string());
+        }
+
+        Ok(AnswerRegistry { path, entries })
+    }
+
+    // Returns the recorded answer in its escaped form
+    pub fn get(&self, input: &str, part: Part) -> Option<&String> {
+        self.entries.get(&(input.to_string(), part))
+    }
+
+    pub fn has_answers_for(&self, input: &str) -> bool {
+        self.entries.keys().any(|(i, _)| i == input)
+    }
+
+    pub fn insert(&mut self, input: &str, part: Part, answer: &Answer) {
+        self.entries.insert((input.to_string(), part), escape(answer));
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut content = String::new();
+
+        for ((input, part), value) in &self.entries {
+            content.push_str(&format!("{}.part{} = {}\n", input, part, value));
+        }
+
+        fs::write(&self.path, content).map_err(|err| format!("Failed to write '{}': {}", self.path.display(), err))
+    }
+}
+
+// Brings an answer into the form it is stored in, so that it can be compared with recorded ones
+pub fn escape(answer: &Answer) -> String {
+    answer.to_string().replace('\n', "\\n")
+}
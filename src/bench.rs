@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::puzzle::DayId;
+use crate::timing::format_duration;
+
+// A median being this much slower than the baseline's counts as regression...
+const REGRESSION_THRESHOLD: f64 = 1.1;
+// ...unless the difference is so small that it is most likely just noise
+const NOISE_FLOOR: Duration = Duration::from_micros(500);
+
+#[derive(Copy, Clone, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            panic!("Cannot compute statistics without samples");
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Stats { min: sorted[0], median, max: sorted[sorted.len() - 1] }
+    }
+}
+
+// The results of benchmarking one input of one day
+pub struct BenchResult {
+    pub day: DayId,
+    pub input: String,
+    pub total: Stats,
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl BenchResult {
+    fn key(&self) -> String {
+        baseline_key(self.day, &self.input)
+    }
+}
+
+// An input that could not be benchmarked, e.g. because its solver failed
+pub struct BenchFailure {
+    pub input: String,
+    pub error: String,
+}
+
+fn baseline_key(day: DayId, input: &str) -> String {
+    format!("{}.day{:0>2}.{}", day.year, day.day, input)
+}
+
+// Median run times of earlier benchmarks, stored as one `YYYY.dayNN.input = nanoseconds` line each
+pub struct Baseline {
+    medians: BTreeMap<String, Duration>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let content = fs::read_to_string(path).map_err(|err| format!("Failed to read baseline '{}': {}", path.display(), err))?;
+
+        let mut medians = BTreeMap::<String, Duration>::new();
+
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let nanos = line.split_once('=').and_then(|(key, value)| {
+                value.trim().parse::<u64>().ok().map(|nanos| (key.trim().to_string(), nanos))
+            });
+
+            match nanos {
+                Some((key, nanos)) => medians.insert(key, Duration::from_nanos(nanos)),
+                None => return Err(format!("Invalid entry in baseline '{}', line {}: '{}'", path.display(), i + 1, line)),
+            };
+        }
+
+        Ok(Baseline { medians })
+    }
+
+    pub fn save(path: &Path, results: &[BenchResult]) -> Result<(), String> {
+        let mut content = String::new();
+
+        for result in results {
+            content.push_str(&format!("{} = {}\n", result.key(), result.total.median.as_nanos()));
+        }
+
+        fs::write(path, content).map_err(|err| format!("Failed to write baseline '{}': {}", path.display(), err))
+    }
+
+    fn median_for(&self, result: &BenchResult) -> Option<Duration> {
+        self.medians.get(&result.key()).copied()
+    }
+}
+
+// Prints the statistics of all benchmarked inputs and, if a baseline is given, how they compare,
+// followed by the inputs that failed. Returns the number of regressions.
+pub fn print_summary(results: &[BenchResult], failures: &[BenchFailure], iterations: usize, baseline: Option<&Baseline>) -> usize {
+    let mut regressions = 0;
+
+    outln!();
+    outln!("Benchmark over {} run(s) per input:", iterations);
+    outln!();
+
+    let mut header = format!("{:>4} | {:>3} | {:<10} | {:>9} | {:>9} | {:>9} | {:>9} | {:>9} | {:>9}",
+                             "Year", "Day", "Input", "parse", "part 1", "part 2", "min", "median", "max");
+
+    if baseline.is_some() {
+        header.push_str(&format!(" | {:>9} | {:>7}", "baseline", "change"));
+    }
+
+    outln!("{}", header);
+    outln!("{}", "-".repeat(header.chars().count()));
+
+    let stage = |stats: &Option<Stats>| stats.map(|s| format_duration(s.median)).unwrap_or_else(|| "-".to_string());
+
+    for result in results {
+        let mut row = format!("{:>4} | {:>3} | {:<10} | {:>9} | {:>9} | {:>9} | {:>9} | {:>9} | {:>9}",
+                              result.day.year,
+                              result.day.day,
+                              result.input,
+                              stage(&result.parse),
+                              stage(&result.part1),
+                              stage(&result.part2),
+                              format_duration(result.total.min),
+                              format_duration(result.total.median),
+                              format_duration(result.total.max));
+
+        if let Some(baseline) = baseline {
+            match baseline.median_for(result) {
+                None => row.push_str(&format!(" | {:>9} | {:>7}", "-", "-")),
+                Some(before) => {
+                    let ratio = result.total.median.as_secs_f64() / before.as_secs_f64().max(f64::MIN_POSITIVE);
+                    let regressed = ratio > REGRESSION_THRESHOLD && result.total.median > before + NOISE_FLOOR;
+
+                    if regressed {
+                        regressions += 1;
+                    }
+
+                    row.push_str(&format!(" | {:>9} | {:>+6.0}%{}",
+                                          format_duration(before),
+                                          (ratio - 1.0) * 100.0,
+                                          if regressed { "  REGRESSION" } else { "" }));
+                }
+            }
+        }
+
+        outln!("{}", row);
+    }
+
+    let summed_medians: Duration = results.iter().map(|r| r.total.median).sum();
+
+    outln!();
+    outln!("Sum of medians: {}", format_duration(summed_medians));
+
+    if baseline.is_some() {
+        outln!("Regressions (> {:.0}% slower than baseline): {}", (REGRESSION_THRESHOLD - 1.0) * 100.0, regressions);
+    }
+
+    if !failures.is_empty() {
+        outln!();
+        outln!("Failed inputs:");
+
+        for failure in failures {
+            outln!("\t{}: {}", failure.input, failure.error);
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{print_summary, Baseline, BenchResult, Stats};
+    use crate::output;
+    use crate::puzzle::DayId;
+    use crate::temp_dir::TempDir;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn result(day: usize, median: Duration) -> BenchResult {
+        let total = Stats::from_samples(&[median]);
+
+        BenchResult { day: DayId::new(2022, day), input: "input_full".to_string(), total, parse: None, part1: None, part2: None }
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let odd = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+        let even = Stats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]);
+
+        assert_eq!((odd.min, odd.median, odd.max), (ms(1), ms(2), ms(3)));
+        assert_eq!((even.min, even.median, even.max), (ms(1), Duration::from_micros(2500), ms(4)));
+    }
+
+    #[test]
+    fn saves_and_loads_baseline() {
+        let dir = TempDir::new("bench");
+        let path = dir.join("baseline");
+
+        Baseline::save(&path, &[result(1, ms(3)), result(2, Duration::from_nanos(1234))]).unwrap();
+
+        let baseline = Baseline::load(&path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "2022.day01.input_full = 3000000\n2022.day02.input_full = 1234\n");
+        assert_eq!(baseline.median_for(&result(1, ms(5))), Some(ms(3)));
+        assert_eq!(baseline.median_for(&result(2, ms(5))), Some(Duration::from_nanos(1234)));
+        assert_eq!(baseline.median_for(&result(3, ms(5))), None);
+
+        assert!(Baseline::load(&dir.join("missing")).err().is_some_and(|err| err.starts_with("Failed to read baseline")));
+
+        std::fs::write(&path, "2022.day01.input_full = 3000000\n\n2022.day02.input_full = fast\n").unwrap();
+
+        assert_eq!(Baseline::load(&path).err(),
+                   Some(format!("Invalid entry in baseline '{}', line 3: '2022.day02.input_full = fast'", path.display())));
+    }
+
+    #[test]
+    fn counts_regressions() {
+        let dir = TempDir::new("bench");
+        let path = dir.join("baseline");
+
+        Baseline::save(&path, &[result(1, ms(1000)), result(2, ms(1000)), result(3, ms(1)), result(4, ms(1))]).unwrap();
+
+        let baseline = Baseline::load(&path).unwrap();
+
+        let regressions = |results: &[BenchResult]| output::capture(|| print_summary(results, &[], 1, Some(&baseline))).0;
+
+        // Exactly 10% slower is not a regression yet
+        assert_eq!(regressions(&[result(1, ms(1100))]), 0);
+        assert_eq!(regressions(&[result(1, ms(1101))]), 1);
+        // Slower by no more than the noise floor is no regression either, however slow relatively
+        assert_eq!(regressions(&[result(3, Duration::from_micros(1500))]), 0);
+        assert_eq!(regressions(&[result(3, Duration::from_micros(1501))]), 1);
+        // Nothing to compare to
+        assert_eq!(regressions(&[result(5, ms(1000))]), 0);
+        assert_eq!(regressions(&[result(1, ms(2000)), result(2, ms(900)), result(3, ms(2)), result(4, ms(1))]), 2);
+        assert_eq!(output::capture(|| print_summary(&[result(1, ms(2000))], &[], 1, None)).0, 0);
+    }
+}
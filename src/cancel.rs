@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::{PuzzleError, PuzzleResult};
+
+// Tells a solver to stop, e.g. because it ran out of its time budget. There is no way to stop a
+// thread from the outside, so solvers that may take long are expected to poll the token in their
+// hot loops:
+//
+//   let token = cancel::current();
+//
+//   loop {
+//       token.check()?;
+//       ...
+//   }
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn check(&self) -> PuzzleResult<()> {
+        match self.is_cancelled() {
+            true => Err(PuzzleError::cancelled()),
+            false => Ok(()),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<CancellationToken> = RefCell::new(CancellationToken::new());
+}
+
+// The token of the solver running on the current thread. Without a time budget it is never
+// cancelled.
+pub fn current() -> CancellationToken {
+    CURRENT.with(|token| token.borrow().clone())
+}
+
+// Makes `token` the one returned by `current` on this thread
+pub fn set_current(token: CancellationToken) {
+    CURRENT.with(|current| *current.borrow_mut() = token);
+}
@@ -0,0 +1,563 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::history::Feedback;
+use crate::log::Filter;
+use crate::puzzle::{InputSelection, Part};
+use crate::report::ReportFormat;
+
+pub const USAGE: &str = "\
+Usage: aoc04 [OPTIONS] [YEAR] [DAYS...]
+       aoc04 new <YEAR> <DAY> <TITLE>
+       aoc04 list
+       aoc04 fetch [--inputs <DIR>] [YEAR] <DAYS...>
+       aoc04 submit [--inputs <DIR>] [YEAR] <DAY> <PART> [ANSWER]
+       aoc04 history [--inputs <DIR>] [YEAR] <DAY> [<PART> <ANSWER> <OUTCOME>]
+
+Runs the Advent of Code puzzles. DAYS can be given as single days (`7`), as
+ranges (`3-9`), or as one of the keywords `all` and `latest`. Without any day
+given, the latest puzzle is run. The days are the ones of YEAR (e.g. `2022`),
+which defaults to the latest year there are puzzles for, except for `all`
+which runs the days of all years unless YEAR is given.
+
+`new` creates the module of a day in `src/yearYYYY/dayNN` and its empty
+inputs in `inputs/YYYY/dayNN` (or AOC_INPUTS), it is registered on the next
+build. `list` prints the days there are puzzles for, with their titles and the
+parts solved so far.
+
+`fetch` downloads the actual inputs of the days, unless they have been
+downloaded already. `submit` submits the answer to a part, the day's answer
+for its actual input unless ANSWER is given. Both need the session cookie of
+your login in AOC_SESSION. A correct answer is recorded in the day's `answers`
+file. After a wrong answer, no answer is submitted until the server takes
+answers again.
+
+Every answer submitted is kept in the day's `history` file with the outcome
+(`correct`, `too-high`, `too-low` or `wrong`). `history` prints it, or
+records an answer tried elsewhere with its OUTCOME. Answers that have been
+rejected before or lie outside the bounds known from answers too high or too
+low are warned about when running the day, and are not submitted.
+
+Options:
+  -t, --test          Only run the test inputs (`input_test`, `input_test2`, ...)
+  -f, --full          Only run the actual (full) input
+      --inputs <DIR>  Look up the inputs in DIR/YYYY/dayNN instead of `inputs`
+                      (or the directory in the AOC_INPUTS environment variable).
+                      Inputs may be gzipped (e.g. `input_full.gz`), see --input
+  -p, --part <PART>   Only report the answer to part 1 or 2
+  -j, --jobs <N>      Solve up to N inputs at the same time, the output is
+                      still printed in order of the days (default: 1)
+      --timeout <SECONDS>
+                      Give up on an input after SECONDS (fractions allowed)
+                      and report it as timed out. A `timeout` parameter in
+                      an input's .params file takes precedence
+  -i, --input <FILE>  Run the given day on FILE instead of its own inputs,
+                      `-` reads from stdin. `.gz` files are decompressed by
+                      running `gzip`, which has to be installed. Puzzle
+                      parameters (like day 15's row) are read from
+                      FILE.params (without the `.gz`), see the days'
+                      `input_*.params` files
+      --verify        Compare the answers with the ones recorded in each day's
+                      `answers` file and print a pass/fail matrix
+      --record        Like --verify, but also record answers not known yet
+      --bench <N>     Run each input N times and print run time statistics
+      --baseline <FILE>
+                      With --bench: compare the medians against FILE and
+                      report regressions
+      --save-baseline <FILE>
+                      With --bench: save the medians to FILE
+      --report <FORMAT>
+                      Instead of the console output, emit one record per
+                      day, input and part as `json` (JSON lines) or `csv`
+  -o, --output <FILE> With --report: write the report to FILE instead of
+                      stdout (the solvers' own output goes to stderr)
+      --test-first    Only run the actual input if the answers for the test
+                      inputs match the ones recorded in the `answers` file
+  -w, --watch         Rebuild and run the day (with --test-first, unless
+                      --test or --full is given) whenever its sources in
+                      `src/yearYYYY/dayNN` or its inputs change
+      --log <FILTER>  Print the solvers' log messages up to the given level
+                      (off, error, warn, info, debug or trace), which can
+                      differ per day: `warn,13=debug,2022.24=trace`
+                      (default: the AOC_LOG environment variable, or info)
+  -h, --help          Print this help
+
+Exit codes:
+  0  all requested puzzles ran (and matched the recorded answers)
+  1  a puzzle could not be run (e.g. unknown day, missing input) or timed
+     out, an answer did not match the recorded one, a benchmark regressed
+     or a reported puzzle failed
+  2  invalid command line";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Selection {
+    All,
+    Latest,
+    Days(Vec<usize>),
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Verification {
+    None,
+    Verify,
+    Record,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Bench {
+    pub iterations: usize,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Report {
+    pub format: ReportFormat,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Help,
+    New { year: usize, day: usize, title: String },
+    List,
+    Fetch { year: Option<usize>, days: Vec<usize>, inputs_root: Option<PathBuf> },
+    Submit { year: Option<usize>, day: usize, part: Part, answer: Option<String>, inputs_root: Option<PathBuf> },
+    History { year: Option<usize>, day: usize, tried: Option<(Part, String, Feedback)>, inputs_root: Option<PathBuf> },
+    Run(RunOptions),
+}
+
+// What to run and how, i.e. everything but the subcommands
+#[derive(Debug, Eq, PartialEq)]
+pub struct RunOptions {
+    pub year: Option<usize>,
+    pub selection: Selection,
+    pub inputs: InputSelection,
+    pub part: Option<Part>,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub input_file: Option<PathBuf>,
+    pub inputs_root: Option<PathBuf>,
+    pub verification: Verification,
+    pub bench: Option<Bench>,
+    pub report: Option<Report>,
+    pub test_first: bool,
+    pub watch: bool,
+    pub log: Option<Filter>,
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.peekable();
+
+    if args.peek().map(String::as_str) == Some("new") {
+        args.next();
+
+        return parse_new_args(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("fetch") {
+        args.next();
+
+        return parse_fetch_args(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("submit") {
+        args.next();
+
+        return parse_submit_args(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("history") {
+        args.next();
+
+        return parse_history_args(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("list") {
+        args.next();
+
+        return match args.next() {
+            Some(arg) if arg == "-h" || arg == "--help" => Ok(Command::Help),
+            Some(arg) => Err(format!("Unexpected argument '{}'", arg)),
+            None => Ok(Command::List),
+        };
+    }
+
+    let mut year: Option<usize> = None;
+    let mut days = Vec::<usize>::new();
+    let mut keyword: Option<Selection> = None;
+    let mut inputs = InputSelection::Both;
+    let mut part: Option<Part> = None;
+    let mut jobs: Option<usize> = None;
+    let mut timeout: Option<Duration> = None;
+    let mut input_file: Option<PathBuf> = None;
+    let mut inputs_root: Option<PathBuf> = None;
+    let mut verification = Verification::None;
+    let mut iterations: Option<usize> = None;
+    let mut baseline: Option<PathBuf> = None;
+    let mut save_baseline: Option<PathBuf> = None;
+    let mut report_format: Option<ReportFormat> = None;
+    let mut report_output: Option<PathBuf> = None;
+    let mut test_first = false;
+    let mut watch = false;
+    let mut log: Option<Filter> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-t" | "--test" => inputs = restrict_inputs(inputs, InputSelection::Test)?,
+            "-f" | "--full" => inputs = restrict_inputs(inputs, InputSelection::Full)?,
+            "-p" | "--part" => {
+                part = Some(args.next().ok_or("Option '--part' requires a part")?.parse()?);
+            }
+            "-j" | "--jobs" => {
+                let n = args.next().ok_or("Option '--jobs' requires a number of jobs")?;
+
+                jobs = match n.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("Invalid number of jobs '{}'", n)),
+                };
+            }
+            "--timeout" => {
+                let secs = args.next().ok_or("Option '--timeout' requires a number of seconds")?;
+
+                timeout = match secs.parse::<f64>().ok().and_then(|s| Duration::try_from_secs_f64(s).ok()) {
+                    Some(t) if !t.is_zero() => Some(t),
+                    _ => return Err(format!("Invalid timeout '{}'", secs)),
+                };
+            }
+            "-i" | "--input" => {
+                let path = args.next().ok_or("Option '--input' requires a file")?;
+
+                input_file = Some(PathBuf::from(path));
+            }
+            "--inputs" => inputs_root = Some(PathBuf::from(args.next().ok_or("Option '--inputs' requires a directory")?)),
+            "--verify" if verification == Verification::None => verification = Verification::Verify,
+            "--verify" => {}
+            "--record" => verification = Verification::Record,
+            "--bench" => {
+                let n = args.next().ok_or("Option '--bench' requires a number of iterations")?;
+
+                iterations = Some(n.parse().map_err(|_| format!("Invalid number of iterations '{}'", n))?);
+            }
+            "--baseline" => baseline = Some(PathBuf::from(args.next().ok_or("Option '--baseline' requires a file")?)),
+            "--save-baseline" => save_baseline = Some(PathBuf::from(args.next().ok_or("Option '--save-baseline' requires a file")?)),
+            "--report" => {
+                let name = args.next().ok_or("Option '--report' requires a format")?;
+
+                report_format = Some(ReportFormat::from_name(&name).ok_or(format!("Invalid report format '{}', must be json or csv", name))?);
+            }
+            "-o" | "--output" => report_output = Some(PathBuf::from(args.next().ok_or("Option '--output' requires a file")?)),
+            "--test-first" => test_first = true,
+            "-w" | "--watch" => watch = true,
+            "--log" => log = Some(Filter::parse(&args.next().ok_or("Option '--log' requires a filter")?)?),
+            "all" | "latest" => {
+                if keyword.is_some() {
+                    return Err("Only one of 'all' and 'latest' can be given".to_string());
+                }
+
+                keyword = Some(if arg == "all" { Selection::All } else { Selection::Latest });
+            }
+            _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+            _ if parse_year(&arg).is_some() => {
+                if year.is_some() {
+                    return Err("Only one year can be given".to_string());
+                }
+
+                year = parse_year(&arg);
+            }
+            _ => days.append(&mut parse_days(&arg)?),
+        }
+    }
+
+    let selection = match keyword {
+        Some(_) if !days.is_empty() => {
+            return Err("Days cannot be combined with 'all' or 'latest'".to_string());
+        }
+        Some(s) => s,
+        None if days.is_empty() => Selection::Latest,
+        None => Selection::Days(days),
+    };
+
+    if input_file.is_some() {
+        match &selection {
+            Selection::Days(days) if days.len() == 1 => {}
+            _ => return Err("Option '--input' requires exactly one day".to_string()),
+        }
+
+        if inputs != InputSelection::Both {
+            return Err("Option '--input' cannot be combined with '--test' or '--full'".to_string());
+        }
+
+        if verification != Verification::None {
+            return Err("Option '--input' cannot be combined with '--verify' or '--record'".to_string());
+        }
+    }
+
+    let bench = match iterations {
+        Some(iterations) => Some(Bench { iterations, baseline, save_baseline }),
+        None if baseline.is_some() || save_baseline.is_some() => {
+            return Err("Options '--baseline' and '--save-baseline' require '--bench'".to_string());
+        }
+        None => None,
+    };
+
+    if bench.is_some() && (verification != Verification::None || input_file.is_some()) {
+        return Err("Option '--bench' cannot be combined with '--verify', '--record' or '--input'".to_string());
+    }
+
+    let report = match report_format {
+        Some(format) => Some(Report { format, output: report_output }),
+        None if report_output.is_some() => return Err("Option '--output' requires '--report'".to_string()),
+        None => None,
+    };
+
+    if report.is_some() && (verification != Verification::None || input_file.is_some() || bench.is_some()) {
+        return Err("Option '--report' cannot be combined with '--verify', '--record', '--input' or '--bench'".to_string());
+    }
+
+    if jobs.is_some() && (verification != Verification::None || input_file.is_some() || bench.is_some() || report.is_some()) {
+        return Err("Option '--jobs' cannot be combined with '--verify', '--record', '--input', '--bench' or '--report'".to_string());
+    }
+
+    if (test_first || watch) && (jobs.is_some() || verification != Verification::None || input_file.is_some() || bench.is_some() || report.is_some()) {
+        return Err("Options '--test-first' and '--watch' cannot be combined with '--jobs', '--verify', '--record', '--input', '--bench' or '--report'".to_string());
+    }
+
+    if test_first && inputs != InputSelection::Both {
+        return Err("Option '--test-first' cannot be combined with '--test' or '--full'".to_string());
+    }
+
+    if watch && !matches!(&selection, Selection::Latest) && !matches!(&selection, Selection::Days(days) if days.len() == 1) {
+        return Err("Option '--watch' requires exactly one day".to_string());
+    }
+
+    Ok(Command::Run(RunOptions {
+        year, selection, inputs, part, jobs: jobs.unwrap_or(1), timeout, input_file, inputs_root, verification, bench, report, test_first, watch, log,
+    }))
+}
+
+fn parse_new_args<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut positional = Vec::<String>::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let [year, day, title] = <[String; 3]>::try_from(positional).map_err(|_| {
+        "Command 'new' requires a year, a day and the puzzle's title".to_string()
+    })?;
+
+    let year = parse_year(&year).ok_or(format!("Invalid year '{}'", year))?;
+
+    let day = parse_day(&day)?;
+
+    Ok(Command::New { year, day, title })
+}
+
+fn parse_fetch_args<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let Some((year, positional, inputs_root)) = parse_client_args(args)? else {
+        return Ok(Command::Help);
+    };
+
+    let mut days = Vec::<usize>::new();
+
+    for arg in &positional {
+        days.append(&mut parse_days(arg)?);
+    }
+
+    if days.is_empty() {
+        return Err("Command 'fetch' requires at least one day".to_string());
+    }
+
+    Ok(Command::Fetch { year, days, inputs_root })
+}
+
+fn parse_submit_args<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let Some((year, positional, inputs_root)) = parse_client_args(args)? else {
+        return Ok(Command::Help);
+    };
+
+    let (day, part, answer) = match &positional[..] {
+        [day, part] => (day, part, None),
+        [day, part, answer] => (day, part, Some(answer.clone())),
+        _ => return Err("Command 'submit' requires a day, a part and optionally the answer".to_string()),
+    };
+
+    let day = parse_day(day)?;
+
+    Ok(Command::Submit { year, day, part: part.parse()?, answer, inputs_root })
+}
+
+fn parse_history_args<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let Some((year, positional, inputs_root)) = parse_client_args(args)? else {
+        return Ok(Command::Help);
+    };
+
+    let (day, tried) = match &positional[..] {
+        [day] => (day, None),
+        [day, part, answer, outcome] => {
+            let feedback = Feedback::parse(outcome).ok_or_else(|| {
+                format!("Invalid outcome '{}', must be correct, too-high, too-low or wrong", outcome)
+            })?;
+
+            (day, Some((part.parse()?, answer.clone(), feedback)))
+        }
+        _ => return Err("Command 'history' requires a day and optionally a part, an answer and its outcome".to_string()),
+    };
+
+    let day = parse_day(day)?;
+
+    Ok(Command::History { year, day, tried, inputs_root })
+}
+
+// The year (if the first argument is one), the other positional arguments and the inputs root of
+// the commands talking to the server, `None` if help is asked for
+#[allow(clippy::type_complexity)]
+fn parse_client_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<(Option<usize>, Vec<String>, Option<PathBuf>)>, String> {
+    let mut positional = Vec::<String>::new();
+    let mut inputs_root: Option<PathBuf> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--inputs" => inputs_root = Some(PathBuf::from(args.next().ok_or("Option '--inputs' requires a directory")?)),
+            // Negative numbers are answers, not options
+            _ if arg.starts_with('-') && arg.parse::<i128>().is_err() => return Err(format!("Unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let year = positional.first().and_then(|arg| parse_year(arg));
+
+    if year.is_some() {
+        positional.remove(0);
+    }
+
+    Ok(Some((year, positional, inputs_root)))
+}
+
+// Years are told apart from days by their four digits, Advent of Code started in 2015
+fn parse_year(arg: &str) -> Option<usize> {
+    match arg.parse::<usize>() {
+        Ok(year) if arg.len() == 4 && year >= 2015 => Some(year),
+        _ => None,
+    }
+}
+
+fn restrict_inputs(current: InputSelection, requested: InputSelection) -> Result<InputSelection, String> {
+    if current != InputSelection::Both && current != requested {
+        return Err("Options '--test' and '--full' are mutually exclusive".to_string());
+    }
+
+    Ok(requested)
+}
+
+// A day of the Advent calendar, i.e. 1 to 25
+fn parse_day(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day '{}', must be 1 to 25", arg)),
+    }
+}
+
+// Parses either a single day ("7") or an inclusive range of days ("3-9")
+fn parse_days(arg: &str) -> Result<Vec<usize>, String> {
+    match arg.split_once('-') {
+        None => Ok(vec![parse_day(arg)?]),
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+
+            if from > to {
+                return Err(format!("Invalid range of days '{}'", arg));
+            }
+
+            Ok((from..=to).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{self, Command, RunOptions, Selection};
+
+    fn args(args: &[&str]) -> Result<Command, String> {
+        cli::parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    fn run_options(arguments: &[&str]) -> RunOptions {
+        match args(arguments) {
+            Ok(Command::Run(options)) => options,
+            other => panic!("Unexpected command {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_year_of_days() {
+        match args(&["2022", "17", "3-4"]) {
+            Ok(Command::Run(options)) => {
+                assert_eq!(options.year, Some(2022));
+                assert_eq!(options.selection, Selection::Days(vec![17, 3, 4]));
+            }
+            other => panic!("Unexpected command {:?}", other),
+        }
+
+        assert_eq!(args(&["2021", "2022"]), Err("Only one year can be given".to_string()));
+    }
+
+    #[test]
+    fn selects_days() {
+        assert_eq!(run_options(&["3-5", "9"]).selection, Selection::Days(vec![3, 4, 5, 9]));
+        assert_eq!(run_options(&["7-7"]).selection, Selection::Days(vec![7]));
+        assert_eq!(run_options(&["all"]).selection, Selection::All);
+        assert_eq!(run_options(&["latest"]).selection, Selection::Latest);
+        assert_eq!(run_options(&[]).selection, Selection::Latest);
+        assert_eq!(run_options(&["2022", "all"]).year, Some(2022));
+        assert_eq!(args(&["9-3"]), Err("Invalid range of days '9-3'".to_string()));
+    }
+
+    #[test]
+    fn rejects_conflicting_options() {
+        let conflicts: &[(&[&str], &str)] = &[
+            (&["-t", "-f", "1"], "Options '--test' and '--full' are mutually exclusive"),
+            (&["all", "latest"], "Only one of 'all' and 'latest' can be given"),
+            (&["all", "3"], "Days cannot be combined with 'all' or 'latest'"),
+            (&["-i", "file", "1-2"], "Option '--input' requires exactly one day"),
+            (&["-i", "file", "-t", "1"], "Option '--input' cannot be combined with '--test' or '--full'"),
+            (&["-i", "file", "--verify", "1"], "Option '--input' cannot be combined with '--verify' or '--record'"),
+            (&["--baseline", "file", "1"], "Options '--baseline' and '--save-baseline' require '--bench'"),
+            (&["--bench", "3", "--record", "1"], "Option '--bench' cannot be combined with '--verify', '--record' or '--input'"),
+            (&["-o", "file", "1"], "Option '--output' requires '--report'"),
+            (&["--report", "csv", "--bench", "3", "1"], "Option '--report' cannot be combined with '--verify', '--record', '--input' or '--bench'"),
+            (&["-j", "4", "--verify", "1"],
+             "Option '--jobs' cannot be combined with '--verify', '--record', '--input', '--bench' or '--report'"),
+            (&["--test-first", "-j", "4", "1"],
+             "Options '--test-first' and '--watch' cannot be combined with '--jobs', '--verify', '--record', '--input', '--bench' or '--report'"),
+            (&["-w", "--report", "json", "1"],
+             "Options '--test-first' and '--watch' cannot be combined with '--jobs', '--verify', '--record', '--input', '--bench' or '--report'"),
+            (&["--test-first", "-t", "1"], "Option '--test-first' cannot be combined with '--test' or '--full'"),
+            (&["-w", "all"], "Option '--watch' requires exactly one day"),
+        ];
+
+        for (arguments, error) in conflicts {
+            assert_eq!(args(arguments), Err(error.to_string()), "{:?}", arguments);
+        }
+    }
+
+    #[test]
+    fn rejects_days_outside_the_calendar() {
+        let invalid = |day: &str| Err(format!("Invalid day '{}', must be 1 to 25", day));
+
+        assert_eq!(args(&["26"]), invalid("26"));
+        assert_eq!(args(&["0-3"]), invalid("0"));
+        assert_eq!(args(&["new", "2022", "99", "Title"]), invalid("99"));
+        assert_eq!(args(&["fetch", "2022", "1", "30"]), invalid("30"));
+        assert_eq!(args(&["submit", "2022", "99", "1", "42"]), invalid("99"));
+        assert_eq!(args(&["history", "2022", "x"]), invalid("x"));
+        assert!(matches!(args(&["submit", "2022", "25", "1", "42"]), Ok(Command::Submit { day: 25, .. })));
+    }
+}
@@ -0,0 +1,287 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use crate::inputs::{InputResolver, FULL_INPUT};
+use crate::puzzle::{DayId, Part};
+
+// The session cookie of the logged in user, inputs differ from user to user
+pub const SESSION_ENV: &str = "AOC_SESSION";
+// Where to talk to instead of the actual site, e.g. a local stand-in
+pub const URL_ENV: &str = "AOC_URL";
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "aoc04 (Advent of Code puzzle runner)";
+
+// Be nice to the server, whatever is requested
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+// Kept next to a day's inputs, holds the time (in seconds since the epoch) before which the server
+// does not take another answer for the day
+const SUBMIT_AFTER_FILE: &str = "submit_after";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    // The input had been downloaded before, inputs never change
+    Cached(PathBuf),
+}
+
+// What the server made of a submitted answer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without telling in which direction (e.g. for answers that are not numbers)
+    Wrong,
+    // Answered too recently, the server takes answers again after the given time
+    RateLimited(Duration),
+    // The part has already been solved, or part 1 has not been solved yet
+    WrongLevel,
+    // Anything else the server said
+    Unknown(String),
+}
+
+// Downloads inputs and submits answers. Talks HTTP by means of curl, as there is no TLS in the
+// standard library.
+pub struct Client {
+    base_url: String,
+    session: String,
+    resolver: InputResolver,
+    request_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, resolver: InputResolver) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            resolver,
+            request_interval: MIN_REQUEST_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    pub fn from_env(resolver: InputResolver) -> Result<Client, String> {
+        let session = env::var(SESSION_ENV).map_err(|_| format!("Set {} to the session cookie of your login", SESSION_ENV))?;
+        let base_url = env::var(URL_ENV).unwrap_or_else(|_| DEFAULT_URL.to_string());
+
+        Ok(Client::new(&base_url, session.trim(), resolver))
+    }
+
+    // The stand-in server in the tests does not need protecting
+    #[cfg(test)]
+    pub fn set_request_interval(&mut self, interval: Duration) {
+        self.request_interval = interval;
+    }
+
+    // Downloads the actual input of a day into its directory, unless that has been done before
+    pub fn fetch_input(&mut self, day: DayId) -> Result<Fetched, String> {
+        let dir = self.resolver.day_dir(day);
+        let path = dir.join(FULL_INPUT);
+
+        // A new day comes with an empty input file to be filled in
+        if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let (status, body) = self.request(&format!("/{}/day/{}/input", day.year, day.day), &[])?;
+
+        match status {
+            200 => {}
+            404 => return Err(format!("The input for {} is not available (yet)", day)),
+            400 | 500 if body.contains("log in") => return Err(format!("The server did not accept the session in {}", SESSION_ENV)),
+            _ => return Err(format!("Failed to fetch the input for {}: status {}", day, status)),
+        }
+
+        fs::create_dir_all(&dir).map_err(|err| format!("Failed to create '{}': {}", dir.display(), err))?;
+
+        // Never leave a partial input behind, it would be taken for the complete one
+        let partial = dir.join(format!("{}.part", FULL_INPUT));
+
+        fs::write(&partial, body)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|err| format!("Failed to write '{}': {}", path.display(), err))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    // Submits the answer to a part of a day. Once the server asked to wait, nothing is sent until
+    // the time is up.
+    pub fn submit(&mut self, day: DayId, part: Part, answer: &str) -> Result<Outcome, String> {
+        let submit_after = self.resolver.day_dir(day).join(SUBMIT_AFTER_FILE);
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let after = fs::read_to_string(&submit_after).ok().and_then(|s| s.trim().parse::<u64>().ok()).map(Duration::from_secs);
+
+        if let Some(after) = after.filter(|&after| after > now) {
+            return Ok(Outcome::RateLimited(after - now));
+        }
+
+        let level = part.to_string();
+        let (status, body) = self.request(&format!("/{}/day/{}/answer", day.year, day.day), &[("level", &level), ("answer", answer)])?;
+
+        if status != 200 {
+            return Err(format!("Failed to submit the answer for {}: status {}", day, status));
+        }
+
+        if let Some(wait) = parse_wait(&body) {
+            let after = (now + wait).as_secs().to_string();
+
+            fs::write(&submit_after, after).map_err(|err| format!("Failed to write '{}': {}", submit_after.display(), err))?;
+        }
+
+        Ok(parse_outcome(&body))
+    }
+
+    // Sends a GET request, or a POST request if there is form data, returning the status and body
+    fn request(&mut self, path: &str, form: &[(&str, &str)]) -> Result<(u16, String), String> {
+        if let Some(last) = self.last_request {
+            thread::sleep(self.request_interval.saturating_sub(last.elapsed()));
+        }
+
+        self.last_request = Some(Instant::now());
+
+        // Passed as config on stdin rather than as arguments, which anyone can see
+        let mut config = String::new();
+
+        config.push_str(&format!("url = {}\n", config_string(&format!("{}{}", self.base_url, path))));
+        config.push_str(&format!("cookie = {}\n", config_string(&format!("session={}", self.session))));
+        config.push_str(&format!("user-agent = {}\n", config_string(USER_AGENT)));
+
+        for (key, value) in form {
+            config.push_str(&format!("data-urlencode = {}\n", config_string(&format!("{}={}", key, value))));
+        }
+
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-", "--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Failed to run curl: {}", err))?;
+
+        child.stdin.take().expect("Failed to pass config to curl").write_all(config.as_bytes())
+            .map_err(|err| format!("Failed to pass config to curl: {}", err))?;
+
+        let output = child.wait_with_output().map_err(|err| format!("Failed to run curl: {}", err))?;
+
+        if !output.status.success() {
+            return Err(format!("Request to {}{} failed: {}", self.base_url, path, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+
+        match output.rsplit_once('\n') {
+            Some((body, status)) => Ok((status.trim().parse().map_err(|_| format!("Invalid status '{}'", status))?, body.to_string())),
+            None => Err(format!("Invalid response from {}{}", self.base_url, path)),
+        }
+    }
+}
+
+// A double quoted value in a curl config file
+fn config_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub fn parse_outcome(html: &str) -> Outcome {
+    if html.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+
+    if html.contains("That's not the right answer") {
+        return match (html.contains("your answer is too high"), html.contains("your answer is too low")) {
+            (true, _) => Outcome::TooHigh,
+            (_, true) => Outcome::TooLow,
+            _ => Outcome::Wrong,
+        };
+    }
+
+    if html.contains("You gave an answer too recently") {
+        return Outcome::RateLimited(parse_wait(html).unwrap_or_default());
+    }
+
+    if html.contains("You don't seem to be solving the right level") {
+        return Outcome::WrongLevel;
+    }
+
+    // The message is the first paragraph of the page's main part
+    let message = Regex::new(r"(?s)<article><p>(.*?)</p></article>").unwrap()
+        .captures(html)
+        .map(|c| c[1].to_string())
+        .unwrap_or_else(|| html.trim().to_string());
+
+    Outcome::Unknown(message)
+}
+
+// How long the server wants us to wait before answering again, e.g. "You have 1m 30s left to wait"
+// or "Please wait one minute before trying again"
+fn parse_wait(html: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let minutes = Regex::new(r"[Pp]lease wait (one|\d+) minutes? before trying again").unwrap();
+
+    if let Some(c) = left.captures(html) {
+        let m = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap_or(0));
+        let s = c[2].parse::<u64>().unwrap_or(0);
+
+        return Some(Duration::from_secs(m * 60 + s));
+    }
+
+    minutes.captures(html).map(|c| {
+        let m = if &c[1] == "one" { 1 } else { c[1].parse::<u64>().unwrap_or(1) };
+
+        Duration::from_secs(m * 60)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::client::{Client, Fetched, Outcome};
+    use crate::inputs::InputResolver;
+    use crate::puzzle::{DayId, Part};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn fetches_input_and_submits_answers() {
+        let root = TempDir::new("client");
+        let stand_in = crate::stand_in::StandIn::start("secret", "1\n2\n3\n", 24000);
+        let day_dir = InputResolver::new(root.to_path_buf()).day_dir(DayId::new(2022, 1));
+        let mut client = Client::new(&stand_in.url, "secret", InputResolver::new(root.to_path_buf()));
+        let day = DayId::new(2022, 1);
+        let submit_after = day_dir.join("submit_after");
+
+        client.set_request_interval(Duration::ZERO);
+
+        let fetched = client.fetch_input(day);
+        let input = std::fs::read_to_string(day_dir.join("input_full"));
+        let fetched_again = client.fetch_input(day);
+
+        let too_low = client.submit(day, Part::One, "23999");
+        let waiting = submit_after.is_file();
+        let rate_limited = client.submit(day, Part::One, "24000");
+
+        std::fs::remove_file(&submit_after).unwrap();
+
+        let correct = client.submit(day, Part::One, "24000");
+        let requests = stand_in.requests();
+
+        assert!(matches!(fetched, Ok(Fetched::Downloaded(_))));
+        assert_eq!(input.unwrap(), "1\n2\n3\n");
+        assert!(matches!(fetched_again, Ok(Fetched::Cached(_))));
+        assert_eq!(too_low, Ok(Outcome::TooLow));
+        assert!(waiting);
+        assert!(matches!(rate_limited, Ok(Outcome::RateLimited(_))));
+        assert_eq!(correct, Ok(Outcome::Correct));
+        assert_eq!(requests, ["GET /2022/day/1/input -> 200", "POST /2022/day/1/answer -> 200", "POST /2022/day/1/answer -> 200"]);
+    }
+}
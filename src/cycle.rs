@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Finds where a simulation starts repeating itself, so its outcome after a huge number of steps
+// can be told without simulating all of them. The simulation is run step by step until a state
+// comes up again, each step yields a key identifying the state and a metric (like the height of
+// a tower) to look up or extrapolate later:
+//
+//   let cycle = Cycle::detect(|n| {
+//       if n > 0 {
+//           simulation.step();
+//       }
+//
+//       (simulation.state(), simulation.height())
+//   });
+//
+//   let height = cycle.extrapolate(1_000_000_000_000);
+//
+// Two states with the same key must behave the same from then on, which is up to the key.
+pub struct Cycle<M> {
+    // The steps before the first state that repeats
+    pub start: usize,
+    // The steps it takes to get back to that state
+    pub period: usize,
+    // The metric of each step up to and including the first repeated state's second time
+    metrics: Vec<M>,
+}
+
+impl<M> Cycle<M> {
+    // Runs the simulation until its states repeat. `step(n)` moves the simulation to step `n`
+    // (the initial state being step 0) and yields the state's key and metric.
+    pub fn detect<K: Eq + Hash, F: FnMut(usize) -> (K, M)>(mut step: F) -> Cycle<M> {
+        let mut seen = HashMap::<K, usize>::new();
+        let mut metrics = Vec::<M>::new();
+
+        loop {
+            let n = metrics.len();
+            let (key, metric) = step(n);
+
+            metrics.push(metric);
+
+            if let Some(&start) = seen.get(&key) {
+                return Cycle { start, period: n - start, metrics };
+            }
+
+            seen.insert(key, n);
+        }
+    }
+
+    // The step before the end of the first repetition that is in the same state as step `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.period,
+        }
+    }
+
+    // The metric of step `n`, which repeats along with the states
+    pub fn metric_at(&self, n: usize) -> &M {
+        &self.metrics[self.equivalent_step(n)]
+    }
+}
+
+impl Cycle<i64> {
+    // The metric of step `n`, for a metric growing by the same amount with each repetition
+    pub fn extrapolate(&self, n: usize) -> i64 {
+        let repetitions = match n < self.start {
+            true => 0,
+            false => (n - self.start) / self.period,
+        };
+
+        let growth = self.metrics[self.start + self.period] - self.metrics[self.start];
+
+        self.metrics[self.equivalent_step(n)] + growth * repetitions as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::Cycle;
+
+    #[test]
+    fn extrapolates_repeating_simulations() {
+        // The states go 0, 1, 2, 3, 4, 2, 3, 4, ... while the total grows by 12 per repetition
+        let (mut state, mut total) = (0, 0);
+
+        let cycle = Cycle::detect(|n| {
+            if n > 0 {
+                state = if state == 4 { 2 } else { state + 1 };
+                total += state + 1;
+            }
+
+            (state, total)
+        });
+
+        assert_eq!((cycle.start, cycle.period), (2, 3));
+        assert_eq!(cycle.equivalent_step(1), 1);
+        assert_eq!(cycle.equivalent_step(8), 2);
+        assert_eq!(*cycle.metric_at(4), 14);
+        assert_eq!(cycle.extrapolate(4), 14);
+        assert_eq!(cycle.extrapolate(7), 26);
+        assert_eq!(cycle.extrapolate(3_000_000_000_002), 12_000_000_000_005);
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::puzzle::DayId;
+use crate::timing::format_duration;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    // The input is malformed, the solver panicked, ...
+    Failed,
+    // The solver ran out of its time budget
+    TimedOut,
+    // The solver noticed it has been cancelled and gave up
+    Cancelled,
+}
+
+// Why a puzzle could not be solved, usually because its input is malformed. Parsers know the
+// offending line (if any), the `Puzzler` fills in the day when reporting the error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PuzzleError {
+    pub kind: ErrorKind,
+    pub day: Option<DayId>,
+    // Line numbers start at 1, like in any editor
+    pub line: Option<usize>,
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl PuzzleError {
+    pub fn new<S: Into<String>>(message: S) -> PuzzleError {
+        PuzzleError { kind: ErrorKind::Failed, day: None, line: None, text: None, message: message.into() }
+    }
+
+    pub fn timed_out(budget: Duration) -> PuzzleError {
+        PuzzleError { kind: ErrorKind::TimedOut, ..PuzzleError::new(format!("Timed out after {}", format_duration(budget))) }
+    }
+
+    pub fn cancelled() -> PuzzleError {
+        PuzzleError { kind: ErrorKind::Cancelled, ..PuzzleError::new("Cancelled") }
+    }
+
+    pub fn at_line(mut self, line: usize, text: &str) -> PuzzleError {
+        self.line = Some(line);
+        self.text = Some(text.to_string());
+
+        self
+    }
+
+    pub fn for_day(mut self, day: DayId) -> PuzzleError {
+        self.day = Some(day);
+
+        self
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "{}: ", day)?;
+        }
+
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if let Some(text) = &self.text {
+            write!(f, " in '{}'", text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<io::Error> for PuzzleError {
+    fn from(err: io::Error) -> Self {
+        PuzzleError::new(format!("Failed to read input: {}", err))
+    }
+}
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+// Parses a number (or anything else implementing `FromStr`) from the input, `what` names it in
+// the error message
+pub fn parse<T: FromStr>(s: &str, what: &str) -> PuzzleResult<T> {
+    s.trim().parse::<T>().map_err(|_| PuzzleError::new(format!("Invalid {} '{}'", what, s)))
+}
+
+// Attaches the line an error occurred at, for parsers that do not know it themselves
+pub trait AtLine<T> {
+    fn at_line(self, line: usize, text: &str) -> PuzzleResult<T>;
+}
+
+impl<T> AtLine<T> for PuzzleResult<T> {
+    fn at_line(self, line: usize, text: &str) -> PuzzleResult<T> {
+        self.map_err(|err| err.at_line(line, text))
+    }
+}
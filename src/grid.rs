@@ -0,0 +1,270 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::point::Point;
+
+// A rectangular grid of cells, stored row by row. Cells are addressed by their x (column) and y
+// (row) coordinate, which start at the grid's origin rather than at 0: a cave from x=494 to x=503
+// has its origin at x=494, a map numbered from 1 has its origin at (1, 1). Coordinates are signed,
+// so moving off the grid in any direction just yields coordinates the grid does not contain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    x_origin: i64,
+    y_origin: i64,
+}
+
+impl<T: Clone> Grid<T> {
+    // A grid with its origin at (0, 0), every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid::with_origin(0, 0, width, height, fill)
+    }
+
+    pub fn with_origin(x_origin: i64, y_origin: i64, width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { cells: vec![fill; width * height], width, height, x_origin, y_origin }
+    }
+
+    // Parses a grid of one cell per character, each line being a row. All lines must have the
+    // same length.
+    pub fn parse<F: FnMut(char) -> PuzzleResult<T>>(lines: &[String], cell: F) -> PuzzleResult<Grid<T>> {
+        Grid::parse_lines(lines, None, cell)
+    }
+
+    // Like `parse`, but lines shorter than the longest one are filled up with `padding` (e.g. as
+    // the input omits trailing whitespace)
+    pub fn parse_padded<F: FnMut(char) -> PuzzleResult<T>>(lines: &[String], padding: T, cell: F) -> PuzzleResult<Grid<T>> {
+        Grid::parse_lines(lines, Some(padding), cell)
+    }
+
+    fn parse_lines<F: FnMut(char) -> PuzzleResult<T>>(lines: &[String], padding: Option<T>, mut cell: F) -> PuzzleResult<Grid<T>> {
+        let width = match &padding {
+            Some(_) => lines.iter().map(|line| line.chars().count()).max().unwrap_or(0),
+            None => lines.first().map_or(0, |line| line.chars().count()),
+        };
+
+        if width == 0 {
+            return Err(PuzzleError::new("The grid is empty"));
+        }
+
+        let mut cells = Vec::<T>::with_capacity(width * lines.len());
+
+        for (i, line) in lines.iter().enumerate() {
+            let row_start = cells.len();
+
+            for c in line.chars() {
+                cells.push(cell(c).map_err(|err| err.at_line(i + 1, line))?);
+            }
+
+            match (&padding, cells.len() - row_start) {
+                (_, n) if n == width => {}
+                (Some(padding), n) => cells.extend(std::iter::repeat_n(padding.clone(), width - n)),
+                (None, _) => return Err(PuzzleError::new(format!("Expected {} cells per row", width)).at_line(i + 1, line)),
+            }
+        }
+
+        Ok(Grid { cells, width, height: lines.len(), x_origin: 0, y_origin: 0 })
+    }
+
+    // Inserts `n` rows filled with `fill` above row `y`, moving the rows below down. The origin
+    // stays, so the cells below get new coordinates.
+    pub fn insert_rows(&mut self, y: i64, n: usize, fill: T) {
+        let at = (y - self.y_origin).clamp(0, self.height as i64) as usize * self.width;
+
+        self.cells.splice(at..at, std::iter::repeat_n(fill, n * self.width));
+        self.height += n;
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // The coordinates of the top left cell
+    pub fn origin(&self) -> (i64, i64) {
+        (self.x_origin, self.y_origin)
+    }
+
+    // Moves the grid, so the top left cell gets the given coordinates
+    pub fn set_origin(&mut self, x: i64, y: i64) {
+        (self.x_origin, self.y_origin) = (x, y);
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.index_of(x, y).is_some()
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // Sets a cell, returning false (and changing nothing) if the grid does not contain it
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    // The cells of a row, left to right
+    pub fn row(&self, y: i64) -> Option<&[T]> {
+        let y = self.offset(y, self.y_origin, self.height)?;
+
+        Some(&self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    // The cells of a column, top to bottom
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> + '_ {
+        let x = self.offset(x, self.x_origin, self.width);
+
+        x.into_iter().flat_map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    // The rows, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // Every cell along with its coordinates, row by row
+    pub fn cells(&self) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.coordinates_of(i), cell))
+    }
+
+    // The coordinates of the cells matching the predicate, row by row
+    pub fn positions<'a, P: Fn(&T) -> bool + 'a>(&'a self, predicate: P) -> impl Iterator<Item = (i64, i64)> + 'a {
+        self.cells().filter(move |(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    // The cells left, right, above and below a cell that are on the grid
+    pub fn neighbours(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64)> + '_ {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)].into_iter().filter(|&(x, y)| self.contains(x, y))
+    }
+
+    // Removes `n` rows starting at row `y`, moving the rows below up
+    pub fn remove_rows(&mut self, y: i64, n: usize) {
+        let first = (y - self.y_origin).clamp(0, self.height as i64) as usize;
+        let last = (first + n).min(self.height);
+
+        self.cells.drain(first * self.width..last * self.width);
+        self.height -= last - first;
+    }
+
+    // One line per row (each ending with a line break), one character per cell
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&cell));
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+
+    fn offset(&self, coordinate: i64, origin: i64, size: usize) -> Option<usize> {
+        let offset = coordinate.checked_sub(origin)?;
+
+        match offset >= 0 && offset < size as i64 {
+            true => Some(offset as usize),
+            false => None,
+        }
+    }
+
+    fn index_of(&self, x: i64, y: i64) -> Option<usize> {
+        let x = self.offset(x, self.x_origin, self.width)?;
+        let y = self.offset(y, self.y_origin, self.height)?;
+
+        Some(y * self.width + x)
+    }
+
+    fn coordinates_of(&self, i: usize) -> (i64, i64) {
+        ((i % self.width) as i64 + self.x_origin, (i / self.width) as i64 + self.y_origin)
+    }
+}
+
+// Indexing panics for cells off the grid, like indexing a `Vec` does
+impl<T> Index<(i64, i64)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (i64, i64)) -> &T {
+        match self.index_of(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!("({}, {}) is not on the {}x{} grid at ({}, {})", x, y, self.width, self.height, self.x_origin, self.y_origin),
+        }
+    }
+}
+
+impl<T> IndexMut<(i64, i64)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (i64, i64)) -> &mut T {
+        let (width, height, x_origin, y_origin) = (self.width, self.height, self.x_origin, self.y_origin);
+
+        match self.index_of(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("({}, {}) is not on the {}x{} grid at ({}, {})", x, y, width, height, x_origin, y_origin),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    #[test]
+    fn addresses_grids_from_their_origin() {
+        let lines: Vec<String> = ["#..", ".#.", "..#"].iter().map(|line| line.to_string()).collect();
+        let mut grid = Grid::parse(&lines, |c| Ok(c == '#')).unwrap();
+
+        grid.set_origin(-1, 10);
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(-1, 10), Some(&true));
+        assert_eq!(grid.get(0, 10), Some(&false));
+        assert_eq!(grid.get(2, 10), None);
+        assert_eq!(grid.get(0, 9), None);
+        assert!(grid.set(1, 10, true));
+        assert!(!grid.set(1, 13, true));
+        assert_eq!(grid.row(10), Some(&[true, false, true][..]));
+        assert_eq!(grid.column(0).copied().collect::<Vec<bool>>(), vec![false, true, false]);
+        assert_eq!(grid.column(5).count(), 0);
+        assert_eq!(grid.neighbours(-1, 10).collect::<Vec<(i64, i64)>>(), vec![(0, 10), (-1, 11)]);
+        assert_eq!(grid.positions(|&cell| cell).collect::<Vec<(i64, i64)>>(), vec![(-1, 10), (1, 10), (0, 11), (1, 12)]);
+
+        grid.insert_rows(10, 1, false);
+        grid.remove_rows(13, 1);
+
+        assert_eq!(grid.render(|&cell| if cell { '#' } else { '.' }), "...\n#.#\n.#.\n");
+
+        let ragged: Vec<String> = ["#..", ".#"].iter().map(|line| line.to_string()).collect();
+
+        assert_eq!(Grid::parse_padded(&ragged, ' ', Ok).unwrap().render(|&c| c), "#..\n.# \n");
+        assert_eq!(Grid::parse(&ragged, Ok).unwrap_err().to_string(), "line 2: Expected 3 cells per row in '.#'");
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers;
+use crate::puzzle::{Answer, Part};
+
+pub const HISTORY_FILE: &str = "history";
+
+// What is known about an answer that was tried, usually from submitting it
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Feedback {
+    pub fn parse(s: &str) -> Option<Feedback> {
+        match s {
+            "correct" => Some(Feedback::Correct),
+            "too-high" => Some(Feedback::TooHigh),
+            "too-low" => Some(Feedback::TooLow),
+            "wrong" => Some(Feedback::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "too-high"),
+            Feedback::TooLow => write!(f, "too-low"),
+            Feedback::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+// The answers tried for a day so far, stored in a file named `history` next to the day's inputs.
+// Unlike the `answers` file it keeps every answer in the order they were tried, e.g.:
+//
+//   input_full.part1 too-low 1000
+//   input_full.part1 too-high 5000
+//   input_full.part1 correct 3068
+//
+// The answers are escaped like in the `answers` file. Empty lines and lines starting with `#` are
+// ignored.
+pub struct AnswerHistory {
+    path: PathBuf,
+    entries: Vec<(String, Part, Feedback, String)>,
+}
+
+impl AnswerHistory {
+    // Loads the history from the given day's directory, a missing file yields an empty history
+    pub fn load(day_dir: &Path) -> Result<AnswerHistory, String> {
+        let path = day_dir.join(HISTORY_FILE);
+        let mut entries = Vec::<(String, Part, Feedback, String)>::new();
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("Failed to read '{}': {}", path.display(), err)),
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid_line = || format!("Invalid entry in '{}', line {}: '{}'", path.display(), i + 1, line);
+
+            let mut fields = line.splitn(3, ' ');
+            let (Some(key), Some(feedback), Some(answer)) = (fields.next(), fields.next(), fields.next()) else {
+                return Err(invalid_line());
+            };
+
+            let (input, part) = key.rsplit_once('.').ok_or_else(invalid_line)?;
+
+            let part = Part::from_key(part).ok_or_else(invalid_line)?;
+
+            let feedback = Feedback::parse(feedback).ok_or_else(invalid_line)?;
+
+            entries.push((input.to_string(), part, feedback, answer.trim().to_string()));
+        }
+
+        Ok(AnswerHistory { path, entries })
+    }
+
+    // The answers tried for a part of an input (in their escaped form), oldest first
+    pub fn tried(&self, input: &str, part: Part) -> Vec<(Feedback, &String)> {
+        self.entries.iter()
+            .filter(|(i, p, _, _)| i == input && *p == part)
+            .map(|(_, _, feedback, answer)| (*feedback, answer))
+            .collect()
+    }
+
+    pub fn record(&mut self, input: &str, part: Part, answer: &Answer, feedback: Feedback) {
+        self.entries.push((input.to_string(), part, feedback, answers::escape(answer)));
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut content = String::new();
+
+        for (input, part, feedback, answer) in &self.entries {
+            content.push_str(&format!("{}.part{} {} {}\n", input, part, feedback, answer));
+        }
+
+        fs::write(&self.path, content).map_err(|err| format!("Failed to write '{}': {}", self.path.display(), err))
+    }
+
+    // The range a numeric answer must be in, going by the answers found too low or too high: the
+    // highest one too low and the lowest one too high (both exclusive)
+    pub fn bounds(&self, input: &str, part: Part) -> (Option<i128>, Option<i128>) {
+        let (mut low, mut high) = (None, None);
+
+        for (feedback, answer) in self.tried(input, part) {
+            match (feedback, answer.parse::<i128>()) {
+                (Feedback::TooLow, Ok(n)) => low = low.max(Some(n)),
+                (Feedback::TooHigh, Ok(n)) => high = Some(high.map_or(n, |high: i128| high.min(n))),
+                _ => {}
+            }
+        }
+
+        (low, high)
+    }
+
+    // Why an answer cannot be right, if the history tells: it has been tried before without
+    // success, or it is out of bounds
+    pub fn check(&self, input: &str, part: Part, answer: &Answer) -> Option<String> {
+        let escaped = answers::escape(answer);
+
+        let rejected = self.tried(input, part).into_iter()
+            .find(|&(feedback, tried)| feedback != Feedback::Correct && *tried == escaped);
+
+        if let Some((feedback, _)) = rejected {
+            return Some(format!("{} has already been tried, it is {}", answer, feedback));
+        }
+
+        let Answer::Number(n) = answer else {
+            return None;
+        };
+
+        match self.bounds(input, part) {
+            (Some(low), _) if *n <= low => Some(format!("{} is too low, {} already is", n, low)),
+            (_, Some(high)) if *n >= high => Some(format!("{} is too high, {} already is", n, high)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::history::{AnswerHistory, Feedback};
+    use crate::puzzle::{Answer, Part};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn warns_about_tried_answers() {
+        let day_dir = TempDir::new("history");
+
+        let mut history = AnswerHistory::load(&day_dir).unwrap();
+
+        history.record("input_full", Part::One, &Answer::from(1000), Feedback::TooLow);
+        history.record("input_full", Part::One, &Answer::from(5000), Feedback::TooHigh);
+        history.record("input_full", Part::One, &Answer::from(1500), Feedback::TooLow);
+        history.record("input_full", Part::One, &Answer::from(3000), Feedback::Wrong);
+        history.save().unwrap();
+
+        let history = AnswerHistory::load(&day_dir).unwrap();
+
+        assert_eq!(history.bounds("input_full", Part::One), (Some(1500), Some(5000)));
+        assert_eq!(history.bounds("input_full", Part::Two), (None, None));
+        assert_eq!(history.check("input_full", Part::One, &Answer::from(1200)), Some("1200 is too low, 1500 already is".to_string()));
+        assert_eq!(history.check("input_full", Part::One, &Answer::from(6000)), Some("6000 is too high, 5000 already is".to_string()));
+        assert_eq!(history.check("input_full", Part::One, &Answer::from(3000)), Some("3000 has already been tried, it is wrong".to_string()));
+        assert_eq!(history.check("input_full", Part::One, &Answer::from(3068)), None);
+        assert_eq!(history.check("input_test", Part::One, &Answer::from(1200)), None);
+    }
+}
@@ -0,0 +1,167 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::puzzle::{DayId, InputSelection};
+
+// Overrides where the inputs are looked up, unless `--inputs` is given
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+pub const TEST_INPUT: &str = "input_test";
+pub const FULL_INPUT: &str = "input_full";
+
+// Inputs may be kept compressed, e.g. `input_full.gz`, which stands in for `input_full`
+const GZIP_SUFFIX: &str = ".gz";
+
+// Finds the inputs of the days below a root directory, laid out as `YYYY/dayNN/`. Each day has its
+// actual input `input_full` and one or more examples: `input_test`, `input_test2`, `input_test3`, ...
+// Any of them may be gzipped. Parameters and recorded answers are kept next to the inputs.
+#[derive(Clone, Debug)]
+pub struct InputResolver {
+    root: PathBuf,
+}
+
+impl InputResolver {
+    pub fn new<P: Into<PathBuf>>(root: P) -> InputResolver {
+        InputResolver { root: root.into() }
+    }
+
+    // The given root, the one from the environment, or else `./inputs` if there is one. Failing
+    // that, the inputs next to the sources the binary was built from are used, so it can be run
+    // from anywhere.
+    pub fn resolve_root(root: Option<PathBuf>) -> PathBuf {
+        if let Some(root) = root.or_else(|| std::env::var_os(INPUTS_ENV).map(PathBuf::from)) {
+            return root;
+        }
+
+        let local = PathBuf::from("./inputs");
+
+        match local.is_dir() {
+            true => local,
+            false => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+        }
+    }
+
+    pub fn day_dir(&self, day: DayId) -> PathBuf {
+        self.root.join(day.year.to_string()).join(format!("day{:0>2}", day.day))
+    }
+
+    // The names of the selected inputs of a day, the test inputs (ordered by their number) first.
+    // A day without any test input still gets `input_test`, so running it reports it as missing.
+    pub fn inputs(&self, day: DayId, selection: InputSelection) -> Vec<String> {
+        let mut inputs = Vec::<String>::new();
+
+        if selection != InputSelection::Full {
+            let mut tests = match fs::read_dir(self.day_dir(day)) {
+                Ok(entries) => entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .map(|name| name.strip_suffix(GZIP_SUFFIX).map_or(name.clone(), str::to_string))
+                    .filter(|name| test_number(name).is_some())
+                    .collect(),
+                Err(_) => Vec::<String>::new(),
+            };
+
+            if tests.is_empty() {
+                tests.push(TEST_INPUT.to_string());
+            }
+
+            inputs.append(&mut tests);
+        }
+
+        if selection != InputSelection::Test {
+            inputs.push(FULL_INPUT.to_string());
+        }
+
+        sort_inputs(&mut inputs);
+        // Both an input and its gzipped version
+        inputs.dedup();
+
+        inputs
+    }
+
+    // The file of one of a day's inputs, or else its gzipped version, or an error telling which
+    // file is missing
+    pub fn input_file(&self, day: DayId, input: &str) -> PuzzleResult<PathBuf> {
+        let path = self.day_dir(day).join(input);
+        let gzipped = self.day_dir(day).join(format!("{}{}", input, GZIP_SUFFIX));
+
+        match (path.is_file(), gzipped.is_file()) {
+            (true, _) => Ok(path),
+            (false, true) => Ok(gzipped),
+            (false, false) => Err(PuzzleError::new(format!(
+                "Missing input file '{}' (inputs are looked up in '{}', see --inputs and {})",
+                path.display(), self.root.display(), INPUTS_ENV))),
+        }
+    }
+}
+
+// The number of a test input, `input_test` being the first one
+fn test_number(input: &str) -> Option<usize> {
+    match input.strip_prefix(TEST_INPUT)? {
+        "" => Some(1),
+        n if n.chars().all(|c| c.is_ascii_digit()) => n.parse::<usize>().ok().filter(|&n| n > 1),
+        _ => None,
+    }
+}
+
+// Orders inputs like they are run: the test inputs by their number, then the actual input
+pub fn sort_inputs(inputs: &mut [String]) {
+    inputs.sort_by_key(|input| (test_number(input).is_none(), test_number(input), input.clone()));
+}
+
+// How an input is referred to in the output, e.g. "test input 2"
+pub fn describe(input: &str) -> String {
+    match (input, test_number(input)) {
+        (_, Some(1)) => "test input".to_string(),
+        (_, Some(n)) => format!("test input {}", n),
+        (FULL_INPUT, _) => "actual input".to_string(),
+        _ => format!("input '{}'", input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inputs::InputResolver;
+    use crate::puzzle::{DayId, InputSelection};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn resolves_test_inputs_in_order() {
+        let root = TempDir::new("inputs");
+        let resolver = InputResolver::new(root.to_path_buf());
+        let day = DayId::new(2022, 1);
+
+        std::fs::create_dir_all(resolver.day_dir(day)).unwrap();
+
+        for name in ["input_test10", "input_test", "input_test2", "input_test.params", "input_full"] {
+            std::fs::write(resolver.day_dir(day).join(name), "").unwrap();
+        }
+
+        let inputs = resolver.inputs(day, InputSelection::Both);
+        let missing = resolver.input_file(DayId::new(2022, 2), "input_full");
+
+        assert_eq!(inputs, vec!["input_test", "input_test2", "input_test10", "input_full"]);
+        assert!(missing.unwrap_err().message.starts_with(&format!("Missing input file '{}'", root.join("2022/day02/input_full").display())));
+    }
+
+    #[test]
+    fn resolves_gzipped_inputs() {
+        let root = TempDir::new("inputs");
+        let resolver = InputResolver::new(root.to_path_buf());
+        let day = DayId::new(2022, 1);
+        let day_dir = resolver.day_dir(day);
+
+        std::fs::create_dir_all(&day_dir).unwrap();
+
+        for name in ["input_test", "input_test.gz", "input_test2.gz", "input_full.gz"] {
+            std::fs::write(day_dir.join(name), "").unwrap();
+        }
+
+        assert_eq!(resolver.inputs(day, InputSelection::Both), vec!["input_test", "input_test2", "input_full"]);
+        // The uncompressed input wins
+        assert_eq!(resolver.input_file(day, "input_test").ok(), Some(day_dir.join("input_test")));
+        assert_eq!(resolver.input_file(day, "input_test2").ok(), Some(day_dir.join("input_test2.gz")));
+        assert_eq!(resolver.input_file(day, "input_full").ok(), Some(day_dir.join("input_full.gz")));
+    }
+}
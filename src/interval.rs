@@ -0,0 +1,161 @@
+use std::fmt;
+
+// A range of integers from `start` up to and including `end`, which is never empty
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+#[allow(dead_code)]
+impl Interval {
+    // None if `start` is past `end`, i.e. if the interval would be empty
+    pub fn new(start: i64, end: i64) -> Option<Interval> {
+        match start <= end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    // The number of integers in the interval
+    pub fn len(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    // Whether all of the other interval is part of this one
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// The union of any number of intervals, kept as the fewest intervals covering the same integers,
+// in ascending order
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+#[allow(dead_code)]
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        self.intervals.push(interval);
+        self.merge();
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // The number of integers covered
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals.iter().any(|interval| interval.contains(value))
+    }
+
+    // The integers within the bounds that are not covered
+    pub fn complement(&self, bounds: Interval) -> IntervalSet {
+        let mut gaps = IntervalSet::new();
+        let mut next = bounds.start;
+
+        for interval in &self.intervals {
+            if let Some(gap) = Interval::new(next, (interval.start - 1).min(bounds.end)) {
+                gaps.intervals.push(gap);
+            }
+
+            next = next.max(interval.end + 1);
+        }
+
+        if let Some(gap) = Interval::new(next, bounds.end) {
+            gaps.intervals.push(gap);
+        }
+
+        gaps
+    }
+
+    // Sorts the intervals and joins the ones overlapping or next to each other
+    fn merge(&mut self) {
+        self.intervals.sort();
+
+        let mut merged = Vec::<Interval>::with_capacity(self.intervals.len());
+
+        for interval in self.intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end + 1 => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+
+        self.intervals = merged;
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet { intervals: iter.into_iter().collect() };
+
+        set.merge();
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::{Interval, IntervalSet};
+
+    #[test]
+    fn merges_intervals() {
+        let interval = |start, end| Interval::new(start, end).unwrap();
+
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(interval(2, 8).len(), 7);
+        assert!(interval(2, 8).contains_interval(&interval(3, 8)));
+        assert!(!interval(2, 8).contains_interval(&interval(3, 9)));
+        assert!(interval(2, 8).overlaps(&interval(8, 9)));
+        assert!(!interval(2, 8).overlaps(&interval(9, 9)));
+        assert_eq!(interval(2, 8).intersection(&interval(5, 12)), Some(interval(5, 8)));
+        assert_eq!(interval(2, 8).intersection(&interval(9, 12)), None);
+
+        let mut set: IntervalSet = [interval(10, 12), interval(-2, 3), interval(4, 5), interval(1, 2)].into_iter().collect();
+
+        assert_eq!(set.intervals(), &[interval(-2, 5), interval(10, 12)]);
+        assert_eq!(set.len(), 11);
+        assert!(set.contains(11) && !set.contains(8));
+        assert_eq!(set.complement(interval(0, 20)).intervals(), &[interval(6, 9), interval(13, 20)]);
+        assert_eq!(set.complement(interval(-5, -3)).intervals(), &[interval(-5, -3)]);
+        assert!(set.complement(interval(10, 11)).is_empty());
+
+        set.insert(interval(6, 9));
+
+        assert_eq!(set.intervals(), &[interval(-2, 12)]);
+    }
+}
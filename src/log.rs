@@ -0,0 +1,222 @@
+use std::cell::Cell;
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::output;
+use crate::puzzle::DayId;
+
+// Debug traces of the solvers, switched on from the command line (`--log`) or the environment
+// instead of by editing the code. Each day can have a level of its own, e.g. `info,13=trace` only
+// traces day 13 of any year. Log lines go where the day's other output goes, see `errln!`:
+//
+//   debug!("Moving {} crates from {} to {}", count, from, to);
+//   trace!("After round {}:\n{}", round, render(&elves));
+//
+// The arguments are only evaluated if the level is enabled for the running day.
+
+// Used unless `--log` is given
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn from_name(name: &str) -> Option<Level> {
+        match name {
+            "off" => Some(Level::Off),
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Off => write!(f, "off"),
+            Level::Error => write!(f, "error"),
+            Level::Warn => write!(f, "warn"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+// The level of each day: the one given for the day (of a single year or of any year), or else
+// the default level
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Filter {
+    default: Level,
+    days: Vec<(Option<usize>, usize, Level)>,
+}
+
+impl Filter {
+    pub const fn new(default: Level) -> Filter {
+        Filter { default, days: Vec::new() }
+    }
+
+    // Parses a comma separated list of a default level and levels for days, given as `DAY=LEVEL`
+    // or `YEAR.DAY=LEVEL`, e.g. `warn,13=debug,2022.24=trace`
+    pub fn parse(s: &str) -> Result<Filter, String> {
+        let mut filter = Filter::new(Level::Info);
+
+        for entry in s.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let invalid_level = |level: &str| format!("Invalid log level '{}', must be off, error, warn, info, debug or trace", level);
+
+            let Some((day, level)) = entry.split_once('=') else {
+                filter.default = Level::from_name(entry).ok_or_else(|| invalid_level(entry))?;
+                continue;
+            };
+
+            let level = Level::from_name(level.trim()).ok_or_else(|| invalid_level(level))?;
+            let invalid_day = || format!("Invalid day '{}' in log filter, must be DAY or YEAR.DAY", day.trim());
+
+            let (year, day) = match day.trim().split_once('.') {
+                Some((year, day)) => (Some(year.parse::<usize>().map_err(|_| invalid_day())?), day),
+                None => (None, day.trim()),
+            };
+
+            filter.days.push((year, day.parse::<usize>().map_err(|_| invalid_day())?, level));
+        }
+
+        Ok(filter)
+    }
+
+    // The filter in the environment, if there is one
+    pub fn from_env() -> Result<Option<Filter>, String> {
+        match std::env::var(LOG_ENV) {
+            Ok(s) => Filter::parse(&s).map(Some).map_err(|err| format!("{} in {}", err, LOG_ENV)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    // Days of a single year take precedence over the same day of any year
+    pub fn level_for(&self, day: Option<DayId>) -> Level {
+        let Some(day) = day else {
+            return self.default;
+        };
+
+        let matching = |year: Option<usize>| {
+            self.days.iter().rev().find(|(y, d, _)| *y == year && *d == day.day).map(|(_, _, level)| *level)
+        };
+
+        matching(Some(day.year)).or_else(|| matching(None)).unwrap_or(self.default)
+    }
+}
+
+// Parses back into the same filter
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.default)?;
+
+        for (year, day, level) in &self.days {
+            match year {
+                Some(year) => write!(f, ",{}.{}={}", year, day, level)?,
+                None => write!(f, ",{}={}", day, level)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Level::Info));
+
+thread_local! {
+    static CURRENT_DAY: Cell<Option<DayId>> = const { Cell::new(None) };
+}
+
+pub fn set_filter(filter: Filter) {
+    *FILTER.write().unwrap_or_else(|err| err.into_inner()) = filter;
+}
+
+// Whether messages of the level are logged for the day running on the current thread
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= FILTER.read().unwrap_or_else(|err| err.into_inner()).level_for(CURRENT_DAY.get())
+}
+
+// Makes the day the one running on the current thread until the returned guard is dropped
+pub fn enter_day(day: DayId) -> DayGuard {
+    DayGuard { previous: CURRENT_DAY.replace(Some(day)) }
+}
+
+pub struct DayGuard {
+    previous: Option<DayId>,
+}
+
+impl Drop for DayGuard {
+    fn drop(&mut self) {
+        CURRENT_DAY.set(self.previous);
+    }
+}
+
+// Log lines are indented like the answers of the day they are about
+pub fn write(level: Level, args: fmt::Arguments) {
+    output::err(format_args!("\t[{}] {}", level, args));
+}
+
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)*));
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        log!($crate::log::Level::Warn, $($arg)*)
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        log!($crate::log::Level::Info, $($arg)*)
+    };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        log!($crate::log::Level::Debug, $($arg)*)
+    };
+}
+
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        log!($crate::log::Level::Trace, $($arg)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::log::{Filter, Level};
+    use crate::puzzle::DayId;
+
+    #[test]
+    fn filters_log_levels_by_day() {
+        let filter = Filter::parse("warn,13=debug,2022.13=trace,24=off").unwrap();
+
+        assert_eq!(filter.level_for(None), Level::Warn);
+        assert_eq!(filter.level_for(Some(DayId::new(2022, 1))), Level::Warn);
+        assert_eq!(filter.level_for(Some(DayId::new(2021, 13))), Level::Debug);
+        assert_eq!(filter.level_for(Some(DayId::new(2022, 13))), Level::Trace);
+        assert_eq!(filter.level_for(Some(DayId::new(2022, 24))), Level::Off);
+        assert_eq!(Filter::parse(&filter.to_string()), Ok(filter));
+        assert_eq!(Filter::parse("13=loud").unwrap_err(), "Invalid log level 'loud', must be off, error, warn, info, debug or trace");
+        assert_eq!(Filter::parse("day13=debug").unwrap_err(), "Invalid day 'day13' in log filter, must be DAY or YEAR.DAY");
+    }
+}
@@ -1,62 +1,445 @@
 extern crate core;
 
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use answers::AnswerRegistry;
+use cli::{Command, RunOptions, Selection, Verification};
+use client::{Client, Fetched, Outcome};
+use history::{AnswerHistory, Feedback};
+use inputs::{InputResolver, FULL_INPUT};
+use puzzle::{Answer, DayId, InputSelection, Part};
+use timing::format_duration;
+
+// The macros are only available to the modules declared after them
+#[macro_use]
+mod log;
+#[macro_use]
+mod output;
+
+mod answers;
+mod bench;
+mod cancel;
+mod cli;
+mod client;
+mod cycle;
+mod error;
+mod grid;
+mod history;
+mod inputs;
+mod interval;
+mod params;
+mod point;
 mod puzzle;
+mod report;
+mod scaffold;
+mod search;
+#[cfg(test)]
+mod stand_in;
+#[cfg(test)]
+mod temp_dir;
+mod timing;
+mod watch;
+
+// The `dayNN` modules and `register_days`, see build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+fn main() -> ExitCode {
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, cli::USAGE);
+
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+
+            ExitCode::SUCCESS
+        }
+        Command::List => {
+            puzzler().list_puzzles();
+
+            ExitCode::SUCCESS
+        }
+        Command::New { year, day, title } => {
+            let src_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+            let inputs_root = InputResolver::resolve_root(None);
+
+            match scaffold::create_day(&src_dir, &inputs_root, DayId::new(year, day), &title) {
+                Ok(created) => {
+                    for path in created {
+                        println!("Created {}", path.display());
+                    }
+
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Fetch { year, days, inputs_root } => exit_code(fetch_inputs(year, &days, inputs_root)),
+        Command::Submit { year, day, part, answer, inputs_root } => exit_code(submit_answer(year, day, part, answer, inputs_root)),
+        Command::History { year, day, tried, inputs_root } => exit_code(answer_history(year, day, tried, inputs_root)),
+        Command::Run(options) => run(options),
+    }
+}
+
+// Runs, verifies, benchmarks or reports on the selected days, or watches one of them
+fn run(options: RunOptions) -> ExitCode {
+    let log = match options.log.map_or_else(log::Filter::from_env, |log| Ok(Some(log))) {
+        Ok(log) => log,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Some(log) = &log {
+        log::set_filter(log.clone());
+    }
+
+    let mut puzzler = puzzler();
+    let inputs_root = InputResolver::resolve_root(options.inputs_root);
+
+    puzzler.set_inputs_root(inputs_root.clone());
+    puzzler.set_input_selection(options.inputs);
+    puzzler.set_part(options.part);
+    puzzler.set_jobs(options.jobs);
+    puzzler.set_timeout(options.timeout);
+    puzzler.set_test_first(options.test_first);
+
+    // Days without a year are the latest year's
+    let year_or_latest = options.year.or_else(|| puzzler.latest_year()).unwrap_or_default();
+
+    let days: Vec<DayId> = match &options.selection {
+        Selection::Days(days) => days.iter().map(|&day| DayId::new(year_or_latest, day)).collect(),
+        Selection::All if options.year.is_some() => puzzler.days_of_year(year_or_latest),
+        Selection::All => puzzler.days(),
+        Selection::Latest => puzzler.days_of_year(year_or_latest).last().into_iter().copied().collect(),
+    };
+
+    let result = if options.watch {
+        match days.first() {
+            Some(&day) => watch_day(day, &inputs_root, options.inputs, options.part, options.timeout, log.as_ref()),
+            None => Err("No puzzle has been added yet".to_string()),
+        }
+    } else if let Some(report) = options.report {
+        match puzzler.report_puzzles(&days, report.format, report.output.as_deref()) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("Some puzzles failed".to_string()),
+            Err(err) => Err(err),
+        }
+    } else if let Some(bench) = options.bench {
+        match puzzler.bench_puzzles(&days, bench.iterations, bench.baseline.as_deref(), bench.save_baseline.as_deref()) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("Some puzzles failed or got slower than the baseline".to_string()),
+            Err(err) => Err(err),
+        }
+    } else if options.verification != Verification::None {
+        match puzzler.verify_puzzles(&days, options.verification == Verification::Record) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("Some answers do not match the recorded ones".to_string()),
+            Err(err) => Err(err),
+        }
+    } else {
+        match (options.selection, options.input_file) {
+            (Selection::Days(_), Some(path)) => puzzler.run_puzzle_with_file(days[0], &path),
+            (Selection::Days(_), None) => puzzler.run_puzzles(&days),
+            (Selection::All, _) => puzzler.run_all_puzzles(options.year),
+            (Selection::Latest, _) => puzzler.run_latest_puzzle(year_or_latest),
+        }
+    };
+
+    exit_code(result)
+}
+
+fn exit_code(result: Result<(), String>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Reruns a day whenever its sources or inputs change, see `watch::watch`
+fn watch_day(day: DayId, inputs_root: &Path, inputs: InputSelection, part: Option<Part>, timeout: Option<Duration>, log: Option<&log::Filter>) -> Result<(), String> {
+    let src_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("year{}", day.year)).join(format!("day{:0>2}", day.day));
+    let inputs_dir = InputResolver::new(inputs_root).day_dir(day);
+
+    let mut args = vec!["--inputs".to_string(), inputs_root.display().to_string()];
+
+    args.push(match inputs {
+        InputSelection::Both => "--test-first".to_string(),
+        InputSelection::Test => "--test".to_string(),
+        InputSelection::Full => "--full".to_string(),
+    });
+
+    if let Some(part) = part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+
+    if let Some(timeout) = timeout {
+        args.extend(["--timeout".to_string(), timeout.as_secs_f64().to_string()]);
+    }
+
+    if let Some(log) = log {
+        args.extend(["--log".to_string(), log.to_string()]);
+    }
+
+    args.extend([day.year.to_string(), day.day.to_string()]);
+
+    watch::watch(&[src_dir, inputs_dir], &args)
+}
+
+// Downloads the actual inputs of the given days, days without a year are the latest year's
+fn fetch_inputs(year: Option<usize>, days: &[usize], inputs_root: Option<PathBuf>) -> Result<(), String> {
+    let year = year.or_else(|| puzzler().latest_year()).ok_or("No puzzle has been added yet")?;
+    let mut client = Client::from_env(InputResolver::new(InputResolver::resolve_root(inputs_root)))?;
+
+    for &day in days {
+        match client.fetch_input(DayId::new(year, day))? {
+            Fetched::Downloaded(path) => println!("Downloaded {}", path.display()),
+            Fetched::Cached(path) => println!("Already downloaded {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+// Submits the given answer, or else the day's answer for its actual input. A correct answer is
+// recorded in the day's answer registry.
+fn submit_answer(year: Option<usize>, day: usize, part: Part, answer: Option<String>, inputs_root: Option<PathBuf>) -> Result<(), String> {
+    let mut puzzler = puzzler();
+    let inputs_root = InputResolver::resolve_root(inputs_root);
+    let resolver = InputResolver::new(&inputs_root);
+
+    puzzler.set_inputs_root(inputs_root);
+
+    let year = year.or_else(|| puzzler.latest_year()).ok_or("No puzzle has been added yet")?;
+    let day = DayId::new(year, day);
+
+    let answer = match answer {
+        Some(answer) => parse_answer(answer),
+        None => puzzler.answer_for(day, FULL_INPUT, part)?.ok_or(format!("There is no answer to part {} of {} yet", part, day))?,
+    };
+
+    // No need to wait for the server to tell what is known already
+    let mut history = AnswerHistory::load(&resolver.day_dir(day))?;
+
+    if let Some(reason) = history.check(FULL_INPUT, part, &answer) {
+        return Err(format!("Not submitting, {}", reason));
+    }
+
+    let mut client = Client::from_env(resolver.clone())?;
+
+    println!("Submitting '{}' as the answer to part {} of {}", answer, part, day);
+
+    let outcome = client.submit(day, part, &answer.to_string())?;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
-fn main() {
+    let feedback = match &outcome {
+        Outcome::Correct => Some(Feedback::Correct),
+        Outcome::TooHigh => Some(Feedback::TooHigh),
+        Outcome::TooLow => Some(Feedback::TooLow),
+        Outcome::Wrong => Some(Feedback::Wrong),
+        _ => None,
+    };
+
+    if let Some(feedback) = feedback {
+        history.record(FULL_INPUT, part, &answer, feedback);
+        history.save()?;
+    }
+
+    match outcome {
+        Outcome::Correct => {
+            let mut registry = AnswerRegistry::load(&resolver.day_dir(day))?;
+
+            registry.insert(FULL_INPUT, part, &answer);
+            registry.save()?;
+
+            println!("That's the right answer, recorded it");
+
+            Ok(())
+        }
+        Outcome::TooHigh => Err("The answer is too high".to_string()),
+        Outcome::TooLow => Err("The answer is too low".to_string()),
+        Outcome::Wrong => Err("The answer is wrong".to_string()),
+        Outcome::RateLimited(wait) => Err(format!("Answered too recently, try again in {}", format_duration(wait))),
+        Outcome::WrongLevel => Err(format!("Part {} of {} has already been solved, or is not unlocked yet", part, day)),
+        Outcome::Unknown(message) => Err(format!("Unexpected response: {}", message)),
+    }
+}
+
+// Records an answer tried outside of `submit` (e.g. on the site) in the day's history, or without
+// one prints the history of the day's actual input
+fn answer_history(year: Option<usize>, day: usize, tried: Option<(Part, String, Feedback)>, inputs_root: Option<PathBuf>) -> Result<(), String> {
+    let year = year.or_else(|| puzzler().latest_year()).ok_or("No puzzle has been added yet")?;
+    let day = DayId::new(year, day);
+    let resolver = InputResolver::new(InputResolver::resolve_root(inputs_root));
+    let mut history = AnswerHistory::load(&resolver.day_dir(day))?;
+
+    if let Some((part, answer, feedback)) = tried {
+        history.record(FULL_INPUT, part, &parse_answer(answer), feedback);
+
+        return history.save();
+    }
+
+    println!("==> {}", day);
+
+    for part in [Part::One, Part::Two] {
+        let tried = history.tried(FULL_INPUT, part);
+
+        if tried.is_empty() {
+            continue;
+        }
+
+        let range = match history.bounds(FULL_INPUT, part) {
+            (None, None) => String::new(),
+            (low, high) => format!(" (between {} and {})",
+                                   low.map_or("-".to_string(), |n| n.to_string()), high.map_or("-".to_string(), |n| n.to_string())),
+        };
+
+        println!("\tPart {}{}:", part, range);
+
+        for (feedback, answer) in tried {
+            println!("\t\t{:<9} {}", feedback.to_string(), answer);
+        }
+    }
+
+    Ok(())
+}
+
+// Answers given on the command line are numbers whenever they look like one, just like the
+// solutions' answers
+fn parse_answer(answer: String) -> Answer {
+    answer.parse::<i128>().map(Answer::from).unwrap_or(Answer::from(answer))
+}
+
+fn puzzler() -> puzzle::Puzzler {
     let mut puzzler = puzzle::Puzzler::new();
 
-    puzzler.add_puzzle(day01::solve);
-    puzzler.add_puzzle(day02::solve);
-    puzzler.add_puzzle(day03::solve);
-    puzzler.add_puzzle(day04::solve);
-    puzzler.add_puzzle(day05::solve);
-    puzzler.add_puzzle(day06::solve);
-    puzzler.add_puzzle(day07::solve);
-    puzzler.add_puzzle(day08::solve);
-    puzzler.add_puzzle(day09::solve);
-    puzzler.add_puzzle(day10::solve);
-    puzzler.add_puzzle(day11::solve);
-    puzzler.add_puzzle(day12::solve);
-    puzzler.add_puzzle(day13::solve);
-    puzzler.add_puzzle(day14::solve);
-    puzzler.add_puzzle(day15::solve);
-    puzzler.add_puzzle(day16::solve);
-    puzzler.add_puzzle(day17::solve);
-    puzzler.add_puzzle(day18::solve);
-    puzzler.add_puzzle(day19::solve);
-    puzzler.add_puzzle(day20::solve);
-    puzzler.add_puzzle(day21::solve);
-    puzzler.add_puzzle(day22::solve);
-    puzzler.add_puzzle(day23::solve);
-    puzzler.add_puzzle(day24::solve);
-    puzzler.add_puzzle(day25::solve);
-
-    // puzzler.run_latest_puzzle();
-    puzzler.run_puzzle_for_day(24);
-}
\ No newline at end of file
+    register_days(&mut puzzler);
+
+    puzzler
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PuzzleResult;
+    use crate::params::Params;
+    use crate::puzzle::{Answer, DayId, Input, Solution};
+
+    fn days(days: &[usize]) -> Vec<DayId> {
+        days.iter().map(|&day| DayId::new(2022, day)).collect()
+    }
+
+    // Generates one test per day, checking the answers for the day's test input against the ones
+    // recorded in its `answers` file
+    macro_rules! verify_test_inputs {
+        ($($name:ident: $day:expr),* $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    if let Err(err) = super::puzzler().verify_test_input(DayId::new(2022, $day)) {
+                        panic!("{}", err);
+                    }
+                }
+            )*
+        };
+    }
+
+    verify_test_inputs!(
+        day01: 1, day02: 2, day03: 3, day04: 4, day05: 5, day06: 6, day07: 7, day08: 8, day09: 9,
+        day10: 10, day11: 11, day12: 12, day13: 13, day14: 14, day15: 15, day16: 16, day17: 17,
+        day18: 18, day19: 19, day20: 20, day21: 21, day22: 22, day23: 23, day24: 24, day25: 25,
+    );
+
+    #[test]
+    fn solves_in_memory_input() {
+        let parsed = crate::year2022::day02::Day02::parse(&mut Input::from_str("A Y\nB X\nC Z\n"), &Params::new()).unwrap();
+
+        assert_eq!(crate::year2022::day02::Day02::part2(&parsed, &Params::new()), Ok(Some(Answer::Number(12))));
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        const TITLE: &'static str = "Broken";
+
+        type Parsed = ();
+
+        fn parse(_: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+            panic!("Broken solver")
+        }
+    }
+
+    struct Todo;
+
+    impl Solution for Todo {
+        const TITLE: &'static str = "Todo";
+
+        type Parsed = ();
+
+        fn parse(_: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+            todo!()
+        }
+    }
+
+    #[test]
+    fn keeps_running_after_panicking_day() {
+        let mut puzzler = crate::puzzle::Puzzler::new();
+
+        puzzler.add_solution::<Broken>(2022, 1);
+        puzzler.add_solution::<Todo>(2022, 2);
+        puzzler.add_solution::<crate::year2022::day03::Day03>(2022, 3);
+
+        assert_eq!(puzzler.run_puzzles(&days(&[1, 2, 3])), Err("1 of 3 day(s) failed".to_string()));
+        assert_eq!(puzzler.run_puzzles(&days(&[2, 3])), Ok(()));
+    }
+
+    #[test]
+    fn runs_days_in_parallel() {
+        let mut puzzler = crate::puzzle::Puzzler::new();
+
+        // The order of registration does not matter
+        puzzler.add_solution::<crate::year2022::day03::Day03>(2022, 3);
+        puzzler.add_solution::<Broken>(2022, 1);
+        puzzler.add_solution::<Todo>(2022, 2);
+        puzzler.set_jobs(4);
+
+        // Day 4 does not exist
+        assert_eq!(puzzler.run_puzzles(&days(&[1, 2, 3, 4])), Err("2 of 4 day(s) failed".to_string()));
+        assert_eq!(puzzler.run_puzzles(&days(&[3, 2])), Ok(()));
+    }
+
+    #[test]
+    fn looks_up_days_by_number() {
+        let mut puzzler = crate::puzzle::Puzzler::new();
+
+        puzzler.add_solution::<crate::year2022::day03::Day03>(2022, 3);
+
+        assert_eq!(puzzler.days(), days(&[3]));
+        assert_eq!(puzzler.run_puzzles(&days(&[1])), Err("1 of 1 day(s) failed".to_string()));
+        assert_eq!(puzzler.run_latest_puzzle(2022), Ok(()));
+    }
+
+    #[test]
+    fn reports_line_of_malformed_input() {
+        let err = crate::year2022::day09::Day09::parse(&mut Input::from_str("R 4\nX 2\nU 4\n"), &Params::new()).unwrap_err();
+
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.text.as_deref(), Some("X 2"));
+    }
+}
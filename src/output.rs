@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::fmt;
+
+// Everything the runner prints about the days and everything the solvers print goes through
+// `outln!` and `errln!`. Usually that is just stdout and stderr, but when days run in parallel each
+// thread captures what its day prints, so the output can be shown in order afterwards instead of
+// being interleaved.
+
+#[derive(Clone, Debug)]
+pub enum Line {
+    Out(String),
+    Err(String),
+}
+
+impl Line {
+    fn print(&self) {
+        match self {
+            Line::Out(s) => println!("{}", s),
+            Line::Err(s) => eprintln!("{}", s),
+        }
+    }
+}
+
+// The lines printed while capturing
+#[derive(Clone, Debug, Default)]
+pub struct Captured {
+    lines: Vec<Line>,
+}
+
+impl Captured {
+    // Prints the captured lines to where they would have gone in the first place
+    pub fn replay(&self) {
+        for line in &self.lines {
+            line.print();
+        }
+    }
+
+    // Passes the captured lines on as if they were printed on the current thread
+    pub fn forward(self) {
+        for line in self.lines {
+            write_line(line);
+        }
+    }
+
+    // Like `forward`, but all of the lines go to stderr, e.g. to keep stdout free for a report
+    pub fn forward_to_stderr(self) {
+        for line in self.lines {
+            match line {
+                Line::Out(s) | Line::Err(s) => write_line(Line::Err(s)),
+            }
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Captured>> = const { RefCell::new(None) };
+}
+
+// Runs `f`, capturing everything it prints on the current thread
+pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, Captured) {
+    // Stops capturing even if `f` panics, otherwise the thread would swallow all later output
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            CAPTURED.with(|c| c.borrow_mut().take());
+        }
+    }
+
+    CAPTURED.with(|c| *c.borrow_mut() = Some(Captured::default()));
+
+    let guard = Guard;
+    let result = f();
+    let captured = CAPTURED.with(|c| c.borrow_mut().take()).unwrap_or_default();
+
+    drop(guard);
+
+    (result, captured)
+}
+
+pub fn is_capturing() -> bool {
+    CAPTURED.with(|c| c.borrow().is_some())
+}
+
+pub fn write_line(line: Line) {
+    let line = CAPTURED.with(|c| match c.borrow_mut().as_mut() {
+        Some(captured) => {
+            captured.lines.push(line);
+
+            None
+        }
+        None => Some(line),
+    });
+
+    if let Some(line) = line {
+        line.print();
+    }
+}
+
+pub fn out(args: fmt::Arguments) {
+    write_line(Line::Out(args.to_string()));
+}
+
+pub fn err(args: fmt::Arguments) {
+    write_line(Line::Err(args.to_string()));
+}
+
+// Like `println!`, but captured when running in parallel
+macro_rules! outln {
+    () => {
+        $crate::output::out(format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::output::out(format_args!($($arg)*))
+    };
+}
+
+// Like `eprintln!`, but captured when running in parallel
+macro_rules! errln {
+    ($($arg:tt)*) => {
+        $crate::output::err(format_args!($($arg)*))
+    };
+}
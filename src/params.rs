@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{self, PuzzleError, PuzzleResult};
+
+pub const PARAMS_EXTENSION: &str = "params";
+
+// Values a puzzle depends on besides its input, like the row to look at in day 15. They differ
+// between the example and the actual puzzle, so they are stored next to each input, in a file
+// named after the input with `.params` appended (e.g. `input_test.params`, also for a gzipped
+// `input_test.gz`):
+//
+//   row = 10
+//   search_max = 20
+//
+// Empty lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    entries: BTreeMap<String, String>,
+}
+
+#[allow(dead_code)]
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    // Loads the parameters of the given input file. Inputs without a parameter file (and stdin)
+    // have no parameters.
+    pub fn load(input_file: &Path) -> PuzzleResult<Params> {
+        if input_file == Path::new("-") {
+            return Ok(Params::new());
+        }
+
+        let path = params_file(input_file);
+
+        match fs::read_to_string(&path) {
+            Ok(content) => Params::parse(&content).map_err(|err| {
+                PuzzleError::new(format!("{} in '{}'", err.message, path.display()))
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Params::new()),
+            Err(err) => Err(PuzzleError::new(format!("Failed to read '{}': {}", path.display(), err))),
+        }
+    }
+
+    pub fn parse(content: &str) -> PuzzleResult<Params> {
+        let mut params = Params::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                PuzzleError::new(format!("Invalid parameter on line {}: '{}'", i + 1, line))
+            })?;
+
+            params = params.with(key.trim(), value.trim());
+        }
+
+        Ok(params)
+    }
+
+    pub fn with(mut self, key: &str, value: &str) -> Params {
+        self.entries.insert(key.to_string(), value.to_string());
+
+        self
+    }
+
+    // A parameter the puzzle cannot do without
+    pub fn get<T: FromStr>(&self, key: &str) -> PuzzleResult<T> {
+        match self.entries.get(key) {
+            Some(value) => error::parse(value, &format!("value of parameter '{}'", key)),
+            None => Err(PuzzleError::new(format!("Missing parameter '{}'", key))),
+        }
+    }
+
+    pub fn get_opt<T: FromStr>(&self, key: &str) -> PuzzleResult<Option<T>> {
+        match self.entries.contains_key(key) {
+            true => self.get(key).map(Some),
+            false => Ok(None),
+        }
+    }
+
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> PuzzleResult<T> {
+        self.get_opt(key).map(|value| value.unwrap_or(default))
+    }
+}
+
+fn params_file(input_file: &Path) -> PathBuf {
+    let input_file = match input_file.extension() {
+        Some(ext) if ext == "gz" => input_file.with_extension(""),
+        _ => input_file.to_path_buf(),
+    };
+
+    let mut name = input_file.into_os_string();
+
+    name.push(".");
+    name.push(PARAMS_EXTENSION);
+
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::params::{params_file, Params};
+    use crate::puzzle::{Answer, Input, Solution};
+    use crate::year2022::day15::Day15;
+
+    #[test]
+    fn reads_puzzle_parameters() {
+        let params = Params::parse("# The example\nrow = 10\n\nsearch_max = 20\n").unwrap();
+        let parsed = Day15::parse(&mut Input::from_str("Sensor at x=8, y=7: closest beacon is at x=2, y=10\n"), &params).unwrap();
+
+        assert_eq!(params.get::<i32>("row"), Ok(10));
+        assert_eq!(params.get_or("timeout", 0.0), Ok(0.0));
+        assert_eq!(Day15::part1(&parsed, &params), Ok(Some(Answer::Number(12))));
+        assert_eq!(Day15::part1(&parsed, &Params::new()).unwrap_err().message, "Missing parameter 'row'");
+        assert_eq!(params_file(Path::new("day15/input_test.gz")), Path::new("day15/input_test.params"));
+        assert_eq!(params_file(Path::new("day15/input_test")), Path::new("day15/input_test.params"));
+    }
+}
@@ -1,103 +1,1419 @@
 use io::BufReader;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs::File;
-use std::io::{self, BufRead, Lines};
-use std::path::Path;
+use std::io::{self, BufRead, Lines, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
 
-type PuzzleFn = fn (lines: &mut Lines<BufReader<File>>, test_run: bool);
+use crate::answers::{self, AnswerRegistry};
+use crate::bench::{self, Baseline, BenchFailure, BenchResult, Stats};
+use crate::cancel::{self, CancellationToken};
+use crate::error::{ErrorKind, PuzzleError, PuzzleResult};
+use crate::history::AnswerHistory;
+use crate::inputs::{self, InputResolver};
+use crate::log;
+use crate::output::{self, Captured};
+use crate::params::Params;
+use crate::report::{self, Record, ReportFormat, Status};
+use crate::timing::{self, format_duration, CpuTimer, StageTimings};
+
+// A day's solution, split into parsing the input and solving both parts based on the parsed
+// input. The runner times each stage and only runs the parts asked for. Anything that differs
+// between the example and the actual puzzle besides the input itself is passed as `Params`.
+pub trait Solution {
+    // The puzzle's title, e.g. "Calorie Counting"
+    const TITLE: &'static str;
+
+    // The parts the solution answers, the others are still to be solved
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    type Parsed;
+
+    fn parse(lines: &mut Input, params: &Params) -> PuzzleResult<Self::Parsed>;
+
+    // A part not (yet) solved has no answer
+    fn part1(_parsed: &Self::Parsed, _params: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(None)
+    }
+
+    fn part2(_parsed: &Self::Parsed, _params: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(None)
+    }
+}
+
+// A solution with its type erased, see `solve`
+type PuzzleFn = fn (lines: &mut Input, params: &Params, parts: &[Part]) -> PuzzleResult<(Answers, StageTimings)>;
+
+// Identifies a puzzle by its year and its day in that year's calendar
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DayId {
+    pub year: usize,
+    pub day: usize,
+}
+
+impl DayId {
+    pub fn new(year: usize, day: usize) -> DayId {
+        DayId { year, day }
+    }
+}
+
+impl fmt::Display for DayId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+// A day's registered solution
+#[derive(Copy, Clone)]
+pub struct Puzzle {
+    pub id: DayId,
+    pub title: &'static str,
+    pub parts: &'static [Part],
+    solve: PuzzleFn,
+}
+
+// Parses the input once and solves the given parts based on it, timing each stage
+fn solve<S: Solution>(lines: &mut Input, params: &Params, parts: &[Part]) -> PuzzleResult<(Answers, StageTimings)> {
+    let start = Instant::now();
+
+    let parsed = S::parse(lines, params)?;
+
+    let mut answers = Answers::new();
+    let mut timings = StageTimings { parse: Some(start.elapsed()), ..StageTimings::default() };
+
+    for part in parts {
+        let part_start = Instant::now();
+
+        match part {
+            Part::One => {
+                answers.part1 = S::part1(&parsed, params)?;
+                timings.part1 = Some(part_start.elapsed());
+            }
+            Part::Two => {
+                answers.part2 = S::part2(&parsed, params)?;
+                timings.part2 = Some(part_start.elapsed());
+            }
+        }
+    }
+
+    timings.total = start.elapsed();
+
+    Ok((answers, timings))
+}
+
+// The input of a puzzle, read line by line. It can be created from files (transparently
+// decompressing `.gz` files), stdin, or anything else implementing `Read`, like in-memory byte
+// slices and strings.
+pub struct Input<'a> {
+    lines: Lines<Box<dyn BufRead + 'a>>,
+    line_number: usize,
+}
+
+#[allow(dead_code)]
+impl<'a> Input<'a> {
+    pub fn from_reader<R: Read + 'a>(reader: R) -> Input<'a> {
+        let buffered: Box<dyn BufRead + 'a> = Box::new(BufReader::new(reader));
+
+        Input { lines: buffered.lines(), line_number: 0 }
+    }
+
+    pub fn from_bytes(bytes: &'a [u8]) -> Input<'a> {
+        Input::from_reader(bytes)
+    }
+
+    pub fn from_str(s: &'a str) -> Input<'a> {
+        Input::from_bytes(s.as_bytes())
+    }
+
+    pub fn from_stdin() -> Input<'a> {
+        Input::from_reader(io::stdin())
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> PuzzleResult<Input<'a>> {
+        let path = path.as_ref();
+        let failed = |err: &dyn fmt::Display| PuzzleError::new(format!("Failed to read '{}': {}", path.display(), err));
+
+        if path.extension().is_some_and(|ext| ext == "gz") {
+            // There is no decompression in the standard library, let gzip do the work. A corrupt or
+            // truncated file must not pass for a shorter input, so gzip has to succeed.
+            let output = Command::new("gzip")
+                .arg("--decompress")
+                .arg("--stdout")
+                .arg(path)
+                .output()
+                .map_err(|err| failed(&format!("Failed to run gzip: {}", err)))?;
+
+            if !output.status.success() {
+                return Err(failed(&format!("gzip failed ({}): {}", output.status, String::from_utf8_lossy(&output.stderr).trim())));
+            }
+
+            return Ok(Input::from_reader(io::Cursor::new(output.stdout)));
+        }
+
+        Ok(Input::from_reader(File::open(path).map_err(|err| failed(&err))?))
+    }
+
+    // Opens the given file, `-` is read as stdin
+    pub fn open(path: &Path) -> PuzzleResult<Input<'a>> {
+        if path == Path::new("-") {
+            return Ok(Input::from_stdin());
+        }
+
+        Input::from_file(path)
+    }
+
+    // The number of the line read last, to point errors at it
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+impl Iterator for Input<'_> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next();
+
+        if line.is_some() {
+            self.line_number += 1;
+        }
+
+        line
+    }
+}
+
+// The answer to one part of a puzzle. Most puzzles ask for a number, some for a string (e.g.
+// letters rendered on a screen).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+// The inverse of `Display`, i.e. "1" or "2" like on the command line. In the answer registry and
+// the history the parts are keyed as "part1" and "part2", see `Part::from_key`.
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("Invalid part '{}', must be 1 or 2", other)),
+        }
+    }
+}
+
+impl Part {
+    // The part of a "part1" or "part2" key
+    pub fn from_key(key: &str) -> Option<Part> {
+        key.strip_prefix("part")?.parse().ok()
+    }
+}
+
+// What a solution found for a single input. A part is `None` if the solution does not (yet) solve
+// it or if it was not run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Answers {
+    pub fn new() -> Answers {
+        Answers::default()
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+// Which of a day's inputs shall be run
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InputSelection {
+    Both,
+    Test,
+    Full,
+}
+
+// Some solvers recurse rather deeply, so worker threads get more stack than the default 2 MiB
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+// How long to wait for a solver to stop after cancelling it
+const CANCEL_GRACE_PERIOD: Duration = Duration::from_millis(100);
 
 pub struct Puzzler {
-    puzzles: Vec<PuzzleFn>
+    puzzles: BTreeMap<DayId, Puzzle>,
+    resolver: InputResolver,
+    inputs: InputSelection,
+    part: Option<Part>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    test_first: bool,
 }
 
 #[allow(dead_code)]
 impl Puzzler {
     pub fn new() -> Puzzler {
-        Puzzler { puzzles: vec![] }
+        Puzzler {
+            puzzles: BTreeMap::new(),
+            resolver: InputResolver::new(InputResolver::resolve_root(None)),
+            inputs: InputSelection::Both,
+            part: None,
+            jobs: 1,
+            timeout: None,
+            test_first: false,
+        }
+    }
+
+    // Adds the solution for the given day of the given year. There can only be one solution per
+    // day.
+    pub fn add_solution<S: Solution>(&mut self, year: usize, day: usize) {
+        let id = DayId::new(year, day);
+        let puzzle = Puzzle { id, title: S::TITLE, parts: S::PARTS, solve: solve::<S> };
+
+        if self.puzzles.insert(id, puzzle).is_some() {
+            panic!("There is more than one solution for {}", id);
+        }
+    }
+
+    // The directory holding the inputs of all years, see `InputResolver`
+    pub fn set_inputs_root(&mut self, root: PathBuf) {
+        self.resolver = InputResolver::new(root);
+    }
+
+    pub fn set_input_selection(&mut self, inputs: InputSelection) {
+        self.inputs = inputs;
+    }
+
+    // Restricts the reported answers to a single part, `None` reports both
+    pub fn set_part(&mut self, part: Option<Part>) {
+        self.part = part;
+    }
+
+    // How many inputs may be solved at the same time, see `run_puzzles`
+    pub fn set_jobs(&mut self, jobs: usize) {
+        self.jobs = jobs.max(1);
+    }
+
+    // The time budget for solving each input, `None` lets solvers take as long as they need. An
+    // input's `timeout` parameter (in seconds) takes precedence.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    // Only runs a day's actual input if the answers for its test inputs match the recorded ones,
+    // which needs the days to be run one after the other
+    pub fn set_test_first(&mut self, test_first: bool) {
+        self.test_first = test_first;
+    }
+
+    // The days there are puzzles for, ordered by year and day
+    pub fn days(&self) -> Vec<DayId> {
+        self.puzzles.keys().copied().collect()
+    }
+
+    // The days of a single year there are puzzles for
+    pub fn days_of_year(&self, year: usize) -> Vec<DayId> {
+        self.puzzles.keys().copied().filter(|id| id.year == year).collect()
+    }
+
+    // The most recent year there are puzzles for
+    pub fn latest_year(&self) -> Option<usize> {
+        self.puzzles.keys().last().map(|id| id.year)
+    }
+
+    // Prints the days there are puzzles for with their titles and the parts solved so far
+    pub fn list_puzzles(&self) {
+        for puzzle in self.puzzles.values() {
+            let parts = match puzzle.parts.is_empty() {
+                true => "-".to_string(),
+                false => puzzle.parts.iter().map(|p| p.to_string()).collect::<Vec<String>>().join(", "),
+            };
+
+            outln!("{} day {:>2}  {:<28} Parts: {}", puzzle.id.year, puzzle.id.day, puzzle.title, parts);
+        }
+    }
+
+    // Runs the selected inputs of a day. An input failing to solve is reported, but does not stop
+    // the day's other inputs from running.
+    pub fn run_puzzle_for_day(&self, day: DayId) -> Result<(), String> {
+        match self.run_day(day)? {
+            DayStatus::Failed => Err(format!("{} failed", day)),
+            DayStatus::TimedOut => Err(format!("{} timed out", day)),
+            _ => Ok(()),
+        }
+    }
+
+    // Runs the given days one after another, or with more than one job, several days and inputs
+    // at the same time. The output is the same either way, in parallel it is just buffered until
+    // it is a day's turn. A failing (or even panicking) day does not stop the others from running.
+    // If there is more than one day, a summary of how the days fared is printed in the end.
+    pub fn run_puzzles(&self, days: &[DayId]) -> Result<(), String> {
+        let start = Instant::now();
+
+        let (statuses, busy) = if self.jobs > 1 && !self.test_first {
+            self.run_days_in_parallel(days)
+        } else {
+            let cpu_timer = CpuTimer::start();
+
+            (days.iter().map(|&day| self.run_day(day).unwrap_or_else(|err| invalid_day(&err))).collect(), cpu_timer.elapsed())
+        };
+
+        let mut summary = BTreeMap::<DayStatus, Vec<DayId>>::new();
+
+        for (&day, status) in days.iter().zip(statuses) {
+            summary.entry(status).or_default().push(day);
+        }
+
+        if days.len() > 1 {
+            print_summary(days.len(), &summary);
+        }
+
+        if self.jobs > 1 {
+            let wall_clock = start.elapsed();
+
+            outln!();
+            outln!("==> Ran with {} jobs", self.jobs);
+            outln!("\t{:<14} {}", "Wall-clock:", format_duration(wall_clock));
+
+            match busy {
+                Some(busy) => outln!("\t{:<14} {} (summed up over all inputs, {:.1}x the wall-clock time)",
+                                     "CPU time:", format_duration(busy), busy.as_secs_f64() / wall_clock.as_secs_f64().max(f64::MIN_POSITIVE)),
+                None => outln!("\t{:<14} n/a", "CPU time:"),
+            }
+        }
+
+        // Running out of time is just another way of failing
+        let failed: usize = [DayStatus::Failed, DayStatus::TimedOut].iter().map(|s| summary.get(s).map_or(0, Vec::len)).sum();
+
+        if failed > 0 {
+            return Err(format!("{} of {} day(s) failed", failed, days.len()));
+        }
+
+        Ok(())
+    }
+
+    // Runs the puzzle of the given day on an arbitrary file instead of the day's own inputs. Its
+    // parameters, if any, are read from the file's own `.params` file.
+    pub fn run_puzzle_with_file(&self, day: DayId, path: &Path) -> Result<(), String> {
+        let puzzle = self.puzzle_for_day(day)?;
+
+        self.print_day_header(day);
+        outln!("--> With input from {}", path.display());
+
+        let (answers, timings) = solve_input(puzzle, day, path, &self.selected_parts(), self.timeout).map_err(|err| err.to_string())?;
+
+        print_answers(&answers, &self.selected_parts());
+        print_timings(&timings);
+
+        Ok(())
+    }
+
+    // Runs the latest day of the given year
+    pub fn run_latest_puzzle(&self, year: usize) -> Result<(), String> {
+        let Some(&day) = self.days_of_year(year).last() else {
+            return Err(format!("No puzzle has been added for {} yet", year));
+        };
+
+        outln!("=> Running puzzle for {}", day);
+
+        self.run_puzzle_for_day(day)
     }
 
-    pub fn add_puzzle(&mut self, puzzle: PuzzleFn) {
-        self.puzzles.push(puzzle)
+    // Runs all days of the given year, or of all years
+    pub fn run_all_puzzles(&self, year: Option<usize>) -> Result<(), String> {
+        let days = match year {
+            Some(year) => self.days_of_year(year),
+            None => self.days(),
+        };
+
+        if days.is_empty() {
+            return Err("No puzzle has been added yet".to_string());
+        }
+
+        self.run_puzzles(&days)
     }
 
-    pub fn run_puzzle_for_day(&self, day: usize) {
-        if day - 1 >= self.puzzles.len() {
-            panic!("Invalid day ('{}')!", day);
+    // Runs the given days and compares their answers with the ones recorded in each day's answer
+    // registry. Prints a pass/fail matrix and returns whether all recorded answers were matched.
+    // With `record` set, answers not yet in a registry are added to it.
+    pub fn verify_puzzles(&self, days: &[DayId], record: bool) -> Result<bool, String> {
+        let parts = self.selected_parts();
+
+        let mut rows = Vec::<(DayId, BTreeMap<String, Vec<Verdict>>)>::new();
+        let mut mismatches = Vec::<String>::new();
+
+        for &day in days {
+            let puzzle = self.puzzle_for_day(day)?;
+            let mut registry = AnswerRegistry::load(&self.resolver.day_dir(day))?;
+            let mut verdicts = BTreeMap::<String, Vec<Verdict>>::new();
+            let mut recorded = false;
+
+            for input in self.selected_inputs(day) {
+                outln!("==> Verifying {} with {}", day, input);
+
+                let answers = match solve_day_input(puzzle, day, &self.resolver, &input, &parts, self.timeout) {
+                    Ok((answers, _)) => answers,
+                    Err(err) => {
+                        let verdict = if err.kind == ErrorKind::TimedOut { Verdict::Timeout } else { Verdict::Error };
+
+                        // The day's other inputs may still be fine, so carry on
+                        mismatches.push(format!("{} ({})", err, input));
+                        verdicts.insert(input, parts.iter().map(|_| verdict).collect());
+
+                        continue;
+                    }
+                };
+
+                let input_verdicts = verdicts.entry(input.clone()).or_default();
+
+                for part in &parts {
+                    let input = input.as_str();
+                    let verdict = check_answer(&registry, input, *part, answers.get(*part));
+
+                    match (&verdict, answers.get(*part)) {
+                        (Verdict::Fail, Some(answer)) => mismatches.push(format!(
+                            "{}, {}, part {}: expected '{}', got '{}'",
+                            day, input, part, registry.get(input, *part).unwrap(), answers::escape(answer))),
+                        (Verdict::Missing, _) => mismatches.push(format!(
+                            "{}, {}, part {}: expected '{}', got no answer",
+                            day, input, part, registry.get(input, *part).unwrap())),
+                        (Verdict::Unrecorded, Some(answer)) if record => {
+                            registry.insert(input, *part, answer);
+
+                            recorded = true;
+                        }
+                        _ => {}
+                    }
+
+                    input_verdicts.push(verdict);
+                }
+            }
+
+            if recorded {
+                registry.save()?;
+            }
+
+            rows.push((day, verdicts));
+        }
+
+        print_verification_matrix(&parts, &rows);
+
+        for mismatch in &mismatches {
+            outln!("{}", mismatch);
         }
 
-        run_puzzle(self.puzzles[day - 1], day);
+        Ok(mismatches.is_empty())
     }
 
-    pub fn run_latest_puzzle(&self) {
-        match self.puzzles.last() {
-            Some(p) => {
-                let day = self.puzzles.len();
+    // Checks the answers for the test inputs of a day against its registry, test inputs without
+    // recorded answers are not run at all
+    pub fn verify_test_input(&self, day: DayId) -> Result<(), String> {
+        let puzzle = self.puzzle_for_day(day)?;
+        let registry = AnswerRegistry::load(&self.resolver.day_dir(day))?;
+
+        for input in self.resolver.inputs(day, InputSelection::Test) {
+            if !registry.has_answers_for(&input) {
+                continue;
+            }
 
-                println!("=> Running puzzle for day {}", day);
+            let (answers, _) = solve_day_input(puzzle, day, &self.resolver, &input, &[Part::One, Part::Two], self.timeout).map_err(|err| err.to_string())?;
 
-                run_puzzle(*p, day);
+            for part in [Part::One, Part::Two] {
+                match check_answer(&registry, &input, part, answers.get(part)) {
+                    Verdict::Fail | Verdict::Missing => {
+                        return Err(format!("{}, {}, part {}: expected {:?}, got {:?}",
+                                           day, input, part, registry.get(&input, part), answers.get(part).map(answers::escape)));
+                    }
+                    _ => {}
+                }
             }
-            None => { println!("No puzzle has been added yet") }
         }
+
+        Ok(())
+    }
+
+    // The answer to a part of one of a day's inputs, e.g. to submit it
+    pub fn answer_for(&self, day: DayId, input: &str, part: Part) -> Result<Option<Answer>, String> {
+        let puzzle = self.puzzle_for_day(day)?;
+        let (answers, _) = solve_day_input(puzzle, day, &self.resolver, input, &[part], self.timeout).map_err(|err| err.to_string())?;
+
+        Ok(answers.get(part).cloned())
     }
 
-    pub fn run_all_puzzles(&self) {
-        if self.puzzles.len() == 0 {
-            println!("No puzzle has been added yet");
+    // Runs each selected input of the given days `iterations` times and prints a summary table
+    // of the run times. If a baseline is given, the medians are compared against it and `false`
+    // is returned in case of a regression, just like when an input fails. The results can be saved
+    // as a new baseline.
+    pub fn bench_puzzles(&self, days: &[DayId], iterations: usize, baseline: Option<&Path>, save_baseline: Option<&Path>) -> Result<bool, String> {
+        if iterations == 0 {
+            return Err("At least one iteration is required to benchmark".to_string());
+        }
+
+        let baseline = match baseline {
+            Some(path) => Some(Baseline::load(path)?),
+            None => None,
+        };
+
+        let mut results = Vec::<BenchResult>::new();
+        let mut failures = Vec::<BenchFailure>::new();
+
+        for &day in days {
+            let puzzle = self.puzzle_for_day(day)?;
+
+            for input in self.selected_inputs(day) {
+                outln!("==> Benchmarking {} with {}", day, input);
+
+                let samples: PuzzleResult<Vec<StageTimings>> = (0..iterations)
+                    .map(|_| solve_day_input(puzzle, day, &self.resolver, &input, &self.selected_parts(), self.timeout).map(|(_, timings)| timings))
+                    .collect();
+
+                // A failing input has nothing to measure, but the others still do
+                let samples = match samples {
+                    Ok(samples) => samples,
+                    Err(err) => {
+                        errln!("\tError: {}", err);
+
+                        failures.push(BenchFailure { input, error: err.to_string() });
+                        continue;
+                    }
+                };
+
+                let stage_stats = |stage: fn(&StageTimings) -> Option<Duration>| -> Option<Stats> {
+                    let durations: Option<Vec<Duration>> = samples.iter().map(stage).collect();
+
+                    durations.map(|d| Stats::from_samples(&d))
+                };
+
+                results.push(BenchResult {
+                    day,
+                    input,
+                    total: Stats::from_samples(&samples.iter().map(|t| t.total).collect::<Vec<Duration>>()),
+                    parse: stage_stats(|t| t.parse),
+                    part1: stage_stats(|t| t.part1),
+                    part2: stage_stats(|t| t.part2),
+                });
+            }
+        }
+
+        let regressions = bench::print_summary(&results, &failures, iterations, baseline.as_ref());
+
+        if let Some(path) = save_baseline {
+            Baseline::save(path, &results)?;
+
+            outln!("Saved baseline to '{}'", path.display());
+        }
+
+        Ok(regressions == 0 && failures.is_empty())
+    }
+
+    // Runs the given days and writes one record per day, input and part to `output` (stdout if not
+    // given). A failing day, be it due to a missing input or a panic, is recorded as such and
+    // does not stop the others from running. Returns whether all days ran without failure.
+    pub fn report_puzzles(&self, days: &[DayId], format: ReportFormat, output: Option<&Path>) -> Result<bool, String> {
+        let mut records = Vec::<Record>::new();
+
+        for &day in days {
+            let puzzle = self.puzzle_for_day(day)?;
+
+            for input in self.selected_inputs(day) {
+                // Only the records go to stdout, whatever the solver prints must not get in between
+                let (result, captured) = output::capture(|| {
+                    solve_day_input(puzzle, day, &self.resolver, &input, &self.selected_parts(), self.timeout)
+                });
+
+                captured.forward_to_stderr();
+
+                for part in self.selected_parts() {
+                    let record = match &result {
+                        Ok((answers, timings)) => Record {
+                            year: day.year,
+                            day: day.day,
+                            input: input.to_string(),
+                            part,
+                            answer: answers.get(part).map(|a| a.to_string()),
+                            elapsed: match part {
+                                Part::One => timings.part1,
+                                Part::Two => timings.part2,
+                            },
+                            total: Some(timings.total),
+                            status: if answers.get(part).is_some() { Status::Ok } else { Status::NoAnswer },
+                        },
+                        Err(err) => Record {
+                            year: day.year,
+                            day: day.day,
+                            input: input.to_string(),
+                            part,
+                            answer: None,
+                            elapsed: None,
+                            total: None,
+                            status: match err.kind {
+                                ErrorKind::TimedOut => Status::TimedOut(err.to_string()),
+                                _ => Status::Failed(err.to_string()),
+                            },
+                        },
+                    };
+
+                    records.push(record);
+                }
+            }
+        }
+
+        let written = match output {
+            Some(path) => File::create(path).and_then(|mut file| report::write_report(&mut file, format, &records)),
+            None => report::write_report(&mut io::stdout(), format, &records),
+        };
+
+        written.map_err(|err| format!("Failed to write report: {}", err))?;
+
+        Ok(records.iter().all(|r| !matches!(r.status, Status::Failed(_) | Status::TimedOut(_))))
+    }
+
+    fn run_day(&self, day: DayId) -> Result<DayStatus, String> {
+        let puzzle = self.puzzle_for_day(day)?;
+        let parts = self.selected_parts();
+        let mut status = DayStatus::Unimplemented;
+
+        let registry = match self.test_first {
+            true => Some(AnswerRegistry::load(&self.resolver.day_dir(day))?),
+            false => None,
+        };
+        let mut tests_pass = true;
+
+        self.print_day_header(day);
+
+        for input in self.selected_inputs(day) {
+            if input == inputs::FULL_INPUT && !tests_pass {
+                outln!("--> Skipping the actual input, the answers for the test inputs do not match the recorded ones");
+
+                status = status.combine(DayStatus::Failed);
+                continue;
+            }
+
+            let (input_status, answers) = run_input(puzzle, day, &self.resolver, &input, &parts, self.timeout);
+
+            status = status.combine(input_status);
+
+            if let Some(registry) = &registry {
+                // Test inputs without recorded answers have nothing to tell
+                tests_pass &= answers.is_some_and(|answers| {
+                    parts.iter().all(|&p| !matches!(check_answer(registry, &input, p, answers.get(p)), Verdict::Fail | Verdict::Missing))
+                });
+            }
+        }
+
+        Ok(status)
+    }
+
+    // Solves each selected input of the given days on a pool of `jobs` threads. The output of each
+    // input is captured and printed as soon as all days before it are done, so it reads just like
+    // a sequential run. Returns the status of each day and the CPU time spent solving summed up
+    // over all inputs, i.e. roughly how long a sequential run would have taken, `None` if it is not
+    // available.
+    fn run_days_in_parallel(&self, days: &[DayId]) -> (Vec<DayStatus>, Option<Duration>) {
+        let parts = self.selected_parts();
+
+        // The inputs to solve, in the order they are printed, and for each day the range of its
+        // inputs in there (or why it cannot be run)
+        let mut work = Vec::<(PuzzleFn, DayId, String)>::new();
+        let mut day_work = Vec::<Result<(usize, usize), String>>::new();
+
+        for &day in days {
+            match self.puzzle_for_day(day) {
+                Ok(puzzle) => {
+                    let first = work.len();
+
+                    work.extend(self.selected_inputs(day).into_iter().map(|input| (puzzle, day, input)));
+                    day_work.push(Ok((first, work.len())));
+                }
+                Err(err) => day_work.push(Err(err)),
+            }
+        }
+
+        let mut finished: Vec<Option<(DayStatus, Captured)>> = vec![None; work.len()];
+        let mut statuses = Vec::<DayStatus>::with_capacity(days.len());
+        let mut busy = Some(Duration::ZERO);
+
+        let next_work = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel::<(usize, DayStatus, Captured, Option<Duration>)>();
+
+        thread::scope(|scope| {
+            for worker in 0..self.jobs.min(work.len()) {
+                let sender = sender.clone();
+                let (work, next_work, parts, timeout, resolver) = (&work, &next_work, &parts, self.timeout, &self.resolver);
+
+                thread::Builder::new()
+                    .name(format!("worker-{}", worker + 1))
+                    .stack_size(WORKER_STACK_SIZE)
+                    .spawn_scoped(scope, move || loop {
+                        let i = next_work.fetch_add(1, Ordering::Relaxed);
+
+                        let Some((puzzle, day, input)) = work.get(i) else {
+                            break;
+                        };
+
+                        let cpu_timer = CpuTimer::start();
+                        let ((status, _), captured) = output::capture(|| run_input(*puzzle, *day, resolver, input, parts, timeout));
+                        let cpu_time = cpu_timer.elapsed();
+
+                        if sender.send((i, status, captured, cpu_time)).is_err() {
+                            break;
+                        }
+                    })
+                    .expect("Failed to spawn worker thread");
+            }
+
+            drop(sender);
+
+            let mut received = receiver.iter();
+
+            // Prints the days in order, waiting for their inputs to be solved where necessary
+            for entry in &day_work {
+                let (first, end) = match entry {
+                    Ok(range) => *range,
+                    Err(err) => {
+                        statuses.push(invalid_day(err));
+
+                        continue;
+                    }
+                };
+
+                while finished[first..end].iter().any(|f| f.is_none()) {
+                    match received.next() {
+                        Some((i, status, captured, cpu_time)) => {
+                            finished[i] = Some((status, captured));
+                            // Unavailable for one input means unavailable for all of them
+                            busy = busy.zip(cpu_time).map(|(busy, cpu_time)| busy + cpu_time);
+                        }
+                        None => break,
+                    }
+                }
+
+                self.print_day_header(work[first].1);
+
+                let mut status = DayStatus::Unimplemented;
+
+                for (input_status, captured) in finished[first..end].iter().flatten() {
+                    captured.replay();
+
+                    status = status.combine(*input_status);
+                }
+
+                statuses.push(status);
+            }
+        });
+
+        (statuses, busy)
+    }
+
+    fn puzzle_for_day(&self, day: DayId) -> Result<PuzzleFn, String> {
+        match self.puzzles.get(&day) {
+            Some(puzzle) => Ok(puzzle.solve),
+            None => Err(format!("There is no puzzle for {}, there are puzzles for {}", day, format_days(&self.days()))),
+        }
+    }
+
+    fn print_day_header(&self, day: DayId) {
+        match self.puzzles.get(&day) {
+            Some(puzzle) => outln!("==> {}: {}", day, puzzle.title),
+            None => outln!("==> {}", day),
+        }
+    }
+
+    fn selected_inputs(&self, day: DayId) -> Vec<String> {
+        self.resolver.inputs(day, self.inputs)
+    }
+
+    fn selected_parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(p) => vec![p],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+// Solves one input of a day, printing its answers and run times, or why it could not be solved.
+// The answers are passed on as well, e.g. to check them before running the next input.
+fn run_input(puzzle: PuzzleFn, day: DayId, resolver: &InputResolver, input: &str, parts: &[Part], timeout: Option<Duration>) -> (DayStatus, Option<Answers>) {
+    outln!("--> With {}", inputs::describe(input));
+
+    match solve_day_input(puzzle, day, resolver, input, parts, timeout) {
+        Ok((answers, timings)) => {
+            print_answers(&answers, parts);
+            print_timings(&timings);
+            warn_about_tried_answers(&resolver.day_dir(day), input, &answers, parts);
+
+            // A day counts as solved as soon as it answers anything
+            let status = match parts.iter().any(|p| answers.get(*p).is_some()) {
+                true => DayStatus::Solved,
+                false => DayStatus::Unimplemented,
+            };
+
+            (status, Some(answers))
+        }
+        Err(err) => {
+            errln!("\tError: {}", err);
+
+            let status = match err.kind {
+                ErrorKind::TimedOut => DayStatus::TimedOut,
+                _ => DayStatus::Failed,
+            };
+
+            (status, None)
+        }
+    }
+}
+
+// A day that cannot be run at all, e.g. because there is no puzzle for it
+fn invalid_day(err: &str) -> DayStatus {
+    errln!("Error: {}", err);
+
+    DayStatus::Failed
+}
+
+// Solves one of the day's own inputs, see `solve_input`
+fn solve_day_input(puzzle: PuzzleFn, day: DayId, resolver: &InputResolver, input: &str, parts: &[Part], timeout: Option<Duration>) -> PuzzleResult<(Answers, StageTimings)> {
+    let input_file = resolver.input_file(day, input).map_err(|err| err.for_day(day))?;
+
+    solve_input(puzzle, day, &input_file, parts, timeout)
+}
+
+// Solves the given parts of an input within the time budget, which is `timeout` unless the input
+// has a `timeout` parameter (in seconds) of its own
+fn solve_input(puzzle: PuzzleFn, day: DayId, input_file: &Path, parts: &[Part], timeout: Option<Duration>) -> PuzzleResult<(Answers, StageTimings)> {
+    let params = Params::load(input_file).map_err(|err| err.for_day(day))?;
+
+    let budget = match params.get_opt::<f64>("timeout").map_err(|err| err.for_day(day))? {
+        Some(secs) => Some(Duration::try_from_secs_f64(secs).map_err(|_| {
+            PuzzleError::new(format!("Invalid timeout '{}'", secs)).for_day(day)
+        })?),
+        None => timeout,
+    };
+
+    match budget {
+        Some(budget) => _run_puzzle_with_budget(puzzle, day, input_file, params, parts, budget),
+        None => _run_puzzle_isolated(puzzle, day, input_file, &params, parts),
+    }
+}
+
+fn _run_puzzle(puzzle: PuzzleFn, day: DayId, input_file: &Path, params: &Params, parts: &[Part]) -> PuzzleResult<(Answers, StageTimings)> {
+    // What the solver logs is filtered by its day
+    let _day = log::enter_day(day);
+
+    let mut lines = Input::open(input_file).map_err(|err| err.for_day(day))?;
+
+    puzzle(&mut lines, params, parts).map_err(|err| err.for_day(day))
+}
+
+// Like `_run_puzzle`, but a panicking solver is reported as error instead of tearing down the
+// whole process. A solver bailing out with `todo!()` or `unimplemented!()` simply has no answers.
+fn _run_puzzle_isolated(puzzle: PuzzleFn, day: DayId, input_file: &Path, params: &Params, parts: &[Part]) -> PuzzleResult<(Answers, StageTimings)> {
+    install_panic_hook();
+
+    match panic::catch_unwind(AssertUnwindSafe(|| _run_puzzle(puzzle, day, input_file, params, parts))) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+
+            if is_unimplemented(&message) {
+                return Ok((Answers::new(), StageTimings::default()));
+            }
+
+            Err(PuzzleError::new(format!("Solver panicked: {}", message)).for_day(day))
+        }
+    }
+}
+
+// Like `_run_puzzle_isolated`, but on a thread of its own, so the runner can stop waiting for the
+// solver once it ran out of its time budget. The solver is cancelled then, but it keeps running
+// until it notices (see `CancellationToken`), if ever.
+fn _run_puzzle_with_budget(puzzle: PuzzleFn, day: DayId, input_file: &Path, params: Params, parts: &[Part], budget: Duration) -> PuzzleResult<(Answers, StageTimings)> {
+    let token = CancellationToken::new();
+    let (sender, receiver) = mpsc::channel();
+
+    // What the solver prints goes where it would have gone without the extra thread
+    let capturing = output::is_capturing();
+
+    let (solver_token, input_file, parts) = (token.clone(), input_file.to_path_buf(), parts.to_vec());
+
+    thread::Builder::new()
+        .name(format!("{}-day{:0>2}", day.year, day.day))
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            cancel::set_current(solver_token);
+
+            let run = || _run_puzzle_isolated(puzzle, day, &input_file, &params, &parts);
+
+            let cpu_timer = CpuTimer::start();
+
+            let (result, captured) = match capturing {
+                true => output::capture(run),
+                false => (run(), Captured::default()),
+            };
+
+            let cpu_time = cpu_timer.elapsed();
+
+            // Nobody listens anymore if the solver ran out of time
+            let _ = sender.send((result, captured, cpu_time));
+        })
+        .map_err(|err| PuzzleError::new(format!("Failed to start solver: {}", err)).for_day(day))?;
+
+    match receiver.recv_timeout(budget) {
+        Ok((result, captured, cpu_time)) => {
+            captured.forward();
+            timing::charge_cpu_time(cpu_time);
+
+            result
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+
+            // A solver polling the token stops right away, anything else has most likely been busy
+            // all the time (and will keep being so)
+            let cpu_time = match receiver.recv_timeout(CANCEL_GRACE_PERIOD) {
+                Ok((_, _, cpu_time)) => cpu_time,
+                Err(_) => Some(budget),
+            };
+
+            timing::charge_cpu_time(cpu_time);
+
+            Err(PuzzleError::timed_out(budget).for_day(day))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(PuzzleError::new("Solver thread died").for_day(day)),
+    }
+}
+
+// Keeps the default output for actual panics, but silences solvers that are not written yet
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if is_unimplemented(&panic_message(info.payload())) {
+                return;
+            }
+
+            // Keep the message with the rest of the day's output when running in parallel
+            match output::is_capturing() {
+                true => errln!("thread '{}' {}", thread::current().name().unwrap_or("<unnamed>"), info),
+                false => default_hook(info),
+            }
+        }));
+    });
+}
+
+// Whether a panic stems from `todo!()` or `unimplemented!()`
+fn is_unimplemented(panic_message: &str) -> bool {
+    panic_message.starts_with("not yet implemented") || panic_message.starts_with("not implemented")
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+
+    "unknown cause".to_string()
+}
+
+fn print_answers(answers: &Answers, parts: &[Part]) {
+    for p in parts {
+        match answers.get(*p) {
+            None => outln!("\tPart {}: -", p),
+            // Multi-line answers (like rendered letters) get a line of their own
+            Some(Answer::Text(s)) if s.contains('\n') => outln!("\tPart {}:\n{}", p, s.trim_end()),
+            Some(answer) => outln!("\tPart {}: {}", p, answer),
+        }
+    }
+}
+
+// Points out answers that the day's history tells cannot be right, so they are not submitted again
+fn warn_about_tried_answers(day_dir: &Path, input: &str, answers: &Answers, parts: &[Part]) {
+    let history = match AnswerHistory::load(day_dir) {
+        Ok(history) => history,
+        Err(err) => {
+            errln!("\tWarning: {}", err);
 
             return;
         }
+    };
 
-        for i in 0..self.puzzles.len() {
-            self.run_puzzle_for_day(i+1)
+    for &part in parts {
+        if let Some(reason) = answers.get(part).and_then(|answer| history.check(input, part, answer)) {
+            errln!("\tWarning: part {}: {}", part, reason);
         }
     }
 }
 
-fn run_puzzle(puzzle: PuzzleFn, day: usize) {
-    let path = format!("./src/day{:0>2}/", day);
+fn print_timings(timings: &StageTimings) {
+    let mut stages = Vec::<String>::new();
 
-    println!("==> Day {}", day);
-    println!("--> With test input");
+    for (name, duration) in [("parse", timings.parse), ("part 1", timings.part1), ("part 2", timings.part2)] {
+        if let Some(d) = duration {
+            stages.push(format!("{} {}", name, format_duration(d)));
+        }
+    }
+
+    stages.push(format!("total {}", format_duration(timings.total)));
 
-    _run_puzzle(puzzle, &path, true);
+    outln!("\tTime: {}", stages.join(", "));
+}
 
-    println!("--> With actual input");
+// How a day fared in a run, over all of its selected inputs
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum DayStatus {
+    Solved,
+    // Not a single part was answered, e.g. because the solver is still a `todo!()`
+    Unimplemented,
+    Failed,
+    TimedOut,
+}
 
-    _run_puzzle(puzzle, &path, false);
+impl DayStatus {
+    // The status of a day given the status of one more of its inputs: a single failing (or timed
+    // out) input fails the whole day, otherwise a single input answering anything makes it solved
+    fn combine(self, other: DayStatus) -> DayStatus {
+        match (self, other) {
+            (DayStatus::Failed, _) | (_, DayStatus::Failed) => DayStatus::Failed,
+            (DayStatus::TimedOut, _) | (_, DayStatus::TimedOut) => DayStatus::TimedOut,
+            (DayStatus::Solved, _) | (_, DayStatus::Solved) => DayStatus::Solved,
+            _ => DayStatus::Unimplemented,
+        }
+    }
 }
 
-fn _run_puzzle(puzzle: PuzzleFn, input_path: &str, test_run: bool) {
-    let input_file = if test_run {"input_test"} else {"input_full"};
+// Lists the (ordered) days by year, condensing consecutive ones into ranges, e.g.
+// "2021 days 1-3, 5; 2022 day 1"
+fn format_days(days: &[DayId]) -> String {
+    let mut years = Vec::<String>::new();
+    let mut i = 0;
+
+    while i < days.len() {
+        let year = days[i].year;
+        let mut ranges = Vec::<String>::new();
+        let mut count = 0;
+
+        while i < days.len() && days[i].year == year {
+            let mut j = i;
+
+            while j + 1 < days.len() && days[j + 1].year == year && days[j + 1].day == days[j].day + 1 {
+                j += 1;
+            }
 
-    puzzle(&mut read_lines(input_path.to_owned() + input_file).expect("Failed to read file"), test_run);
+            ranges.push(match i == j {
+                true => days[i].day.to_string(),
+                false => format!("{}-{}", days[i].day, days[j].day),
+            });
+
+            count += j - i + 1;
+            i = j + 1;
+        }
+
+        years.push(format!("{} {} {}", year, if count == 1 { "day" } else { "days" }, ranges.join(", ")));
+    }
+
+    match years.is_empty() {
+        true => "-".to_string(),
+        false => years.join("; "),
+    }
+}
+
+fn print_summary(day_count: usize, summary: &BTreeMap<DayStatus, Vec<DayId>>) {
+    outln!();
+    outln!("==> Summary of {} days", day_count);
+
+    for (status, name) in [(DayStatus::Solved, "Solved"), (DayStatus::Unimplemented, "Unimplemented"), (DayStatus::Failed, "Failed"), (DayStatus::TimedOut, "Timed out")] {
+        let mut days = summary.get(&status).cloned().unwrap_or_default();
+
+        days.sort();
+
+        outln!("\t{:<14} {}", format!("{}:", name), format_days(&days));
+    }
 }
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<Lines<BufReader<File>>> where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(BufReader::new(file).lines())
+// Outcome of comparing a computed answer with the recorded one
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Verdict {
+    Pass,
+    Fail,
+    // There is an answer, but nothing has been recorded to compare it with
+    Unrecorded,
+    // An answer has been recorded, but the solver did not give one
+    Missing,
+    // Neither recorded nor answered, e.g. the part is not solved yet
+    Unanswered,
+    // The solver failed on the input
+    Error,
+    // The solver ran out of time on the input
+    Timeout,
 }
 
-pub fn read_n_lines(lines: &mut Lines<BufReader<File>>, n: usize) -> Vec<String> {
+impl Verdict {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "ok",
+            Verdict::Fail => "FAIL",
+            Verdict::Unrecorded => "new",
+            Verdict::Missing => "MISS",
+            Verdict::Unanswered => "-",
+            Verdict::Error => "ERR",
+            Verdict::Timeout => "TIMEOUT",
+        }
+    }
+}
+
+fn check_answer(registry: &AnswerRegistry, input: &str, part: Part, answer: Option<&Answer>) -> Verdict {
+    match (registry.get(input, part), answer) {
+        (Some(expected), Some(answer)) if *expected == answers::escape(answer) => Verdict::Pass,
+        (Some(_), Some(_)) => Verdict::Fail,
+        (Some(_), None) => Verdict::Missing,
+        (None, Some(_)) => Verdict::Unrecorded,
+        (None, None) => Verdict::Unanswered,
+    }
+}
+
+// Prints one column per input and part, days without some input (e.g. a second test input) just
+// have empty cells there
+fn print_verification_matrix(parts: &[Part], rows: &[(DayId, BTreeMap<String, Vec<Verdict>>)]) {
+    let mut inputs: Vec<String> = rows.iter().flat_map(|(_, verdicts)| verdicts.keys().cloned()).collect();
+
+    inputs.sort();
+    inputs.dedup();
+    inputs::sort_inputs(&mut inputs);
+
+    let mut header = String::from("Year  Day");
+
+    for input in &inputs {
+        for part in parts {
+            header.push_str(&format!(" | {:>12}", format!("{} p{}", input.trim_start_matches("input_"), part)));
+        }
+    }
+
+    outln!();
+    outln!("{}", header);
+    outln!("{}", "-".repeat(header.len()));
+
+    for (day, verdicts) in rows {
+        let mut row = format!("{:>4}  {:>3}", day.year, day.day);
+
+        for input in &inputs {
+            match verdicts.get(input) {
+                Some(verdicts) => verdicts.iter().for_each(|v| row.push_str(&format!(" | {:>12}", v.symbol()))),
+                None => parts.iter().for_each(|_| row.push_str(&format!(" | {:>12}", ""))),
+            }
+        }
+
+        outln!("{}", row);
+    }
+
+    outln!();
+}
+
+pub fn read_n_lines(lines: &mut Input, n: usize) -> PuzzleResult<Vec<String>> {
     let mut vec = Vec::<String>::new();
 
     for _ in 0..n {
         match lines.next() {
-            None => return vec,
-            Some(line) => vec.push(line.expect("Failed to read line"))
+            None => return Ok(vec),
+            Some(line) => vec.push(line?)
         }
     }
 
-    return vec;
+    return Ok(vec);
 }
 
-pub fn read_all_lines(lines: &mut Lines<BufReader<File>>) -> Vec<String> {
+pub fn read_all_lines(lines: &mut Input) -> PuzzleResult<Vec<String>> {
     let mut all_lines = Vec::<String>::new();
 
     for line in lines {
-        all_lines.push(line.expect("Failed to read line"));
+        all_lines.push(line?);
+    }
+
+    return Ok(all_lines);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crate::error::{PuzzleError, PuzzleResult};
+    use crate::inputs::InputResolver;
+    use crate::params::Params;
+    use crate::puzzle::{DayId, Input, Puzzler, Solution};
+    use crate::temp_dir::TempDir;
+
+    fn days(days: &[usize]) -> Vec<DayId> {
+        days.iter().map(|&day| DayId::new(2022, day)).collect()
+    }
+
+    static STOPPED_SOLVERS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Endless;
+
+    impl Solution for Endless {
+        const TITLE: &'static str = "Endless";
+
+        type Parsed = ();
+
+        fn parse(_: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+            let token = crate::cancel::current();
+
+            while !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            STOPPED_SOLVERS.fetch_add(1, Ordering::Relaxed);
+
+            Err(PuzzleError::cancelled())
+        }
+    }
+
+    #[test]
+    fn cancels_solver_running_out_of_time() {
+        let mut puzzler = Puzzler::new();
+
+        puzzler.add_solution::<Endless>(2022, 1);
+        puzzler.set_timeout(Some(Duration::from_millis(20)));
+
+        assert_eq!(puzzler.run_puzzles(&days(&[1])), Err("1 of 1 day(s) failed".to_string()));
+        // Both the test and the actual input
+        assert_eq!(STOPPED_SOLVERS.load(Ordering::Relaxed), 2);
+    }
+
+    struct Busy;
+
+    impl Solution for Busy {
+        const TITLE: &'static str = "Busy";
+
+        type Parsed = ();
+
+        fn parse(_: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+            let start = Instant::now();
+
+            while start.elapsed() < Duration::from_millis(50) {
+                std::hint::spin_loop();
+            }
+
+            Ok(())
+        }
     }
 
-    return all_lines;
-}
\ No newline at end of file
+    #[test]
+    fn sums_up_cpu_time_of_busy_solvers() {
+        let mut puzzler = Puzzler::new();
+
+        puzzler.add_solution::<Busy>(2022, 1);
+        puzzler.set_jobs(2);
+
+        let (_, busy) = puzzler.run_days_in_parallel(&days(&[1]));
+
+        // Also when the solvers run on a thread of their own, to stop them in time
+        puzzler.set_timeout(Some(Duration::from_secs(10)));
+
+        let (_, busy_with_timeout) = puzzler.run_days_in_parallel(&days(&[1]));
+
+        assert!(busy.is_some_and(|busy| busy > Duration::ZERO));
+        assert!(busy_with_timeout.is_some_and(|busy| busy > Duration::ZERO));
+    }
+
+    #[test]
+    fn runs_actual_input_after_matching_test_answers() {
+        let root = TempDir::new("test-first");
+        let day_dir = InputResolver::new(root.to_path_buf()).day_dir(DayId::new(2022, 3));
+        let mut puzzler = Puzzler::new();
+
+        std::fs::create_dir_all(&day_dir).unwrap();
+        std::fs::copy("inputs/2022/day03/input_test", day_dir.join("input_test")).unwrap();
+        std::fs::copy("inputs/2022/day03/input_test", day_dir.join("input_full")).unwrap();
+
+        puzzler.add_solution::<crate::year2022::day03::Day03>(2022, 3);
+        puzzler.set_inputs_root(root.to_path_buf());
+        puzzler.set_test_first(true);
+
+        // Nothing recorded yet, so nothing to hold the actual input back
+        let unrecorded = puzzler.run_puzzles(&days(&[3]));
+
+        std::fs::write(day_dir.join("answers"), "input_test.part2 = 1\n").unwrap();
+
+        let mismatching = puzzler.run_puzzles(&days(&[3]));
+
+        std::fs::write(day_dir.join("answers"), "input_test.part2 = 70\n").unwrap();
+
+        let matching = puzzler.run_puzzles(&days(&[3]));
+
+        assert_eq!(unrecorded, Ok(()));
+        assert_eq!(mismatching, Err("1 of 1 day(s) failed".to_string()));
+        assert_eq!(matching, Ok(()));
+    }
+}
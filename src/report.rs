@@ -0,0 +1,219 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::puzzle::Part;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl ReportFormat {
+    pub fn from_name(name: &str) -> Option<ReportFormat> {
+        match name {
+            "json" => Some(ReportFormat::Json),
+            "csv" => Some(ReportFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Ok,
+    // The solver ran fine but does not answer this part
+    NoAnswer,
+    // The solver could not be run or panicked, holds the reason
+    Failed(String),
+    // The solver ran out of its time budget, holds the error message
+    TimedOut(String),
+}
+
+// One line of a report: the outcome of one part of one day for one of its inputs
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    pub input: String,
+    pub part: Part,
+    pub answer: Option<String>,
+    // Time spent on this part, parsing the input not included
+    pub elapsed: Option<Duration>,
+    // Time spent on the whole input (parsing and both parts)
+    pub total: Option<Duration>,
+    pub status: Status,
+}
+
+impl Record {
+    fn status_name(&self) -> &'static str {
+        match self.status {
+            Status::Ok => "ok",
+            Status::NoAnswer => "no_answer",
+            Status::Failed(_) => "failed",
+            Status::TimedOut(_) => "timed_out",
+        }
+    }
+
+    fn error(&self) -> Option<&String> {
+        match &self.status {
+            Status::Failed(msg) | Status::TimedOut(msg) => Some(msg),
+            _ => None,
+        }
+    }
+}
+
+const CSV_HEADER: &str = "year,day,input,part,answer,elapsed_ns,total_ns,status,error";
+
+pub fn write_report<W: Write>(out: &mut W, format: ReportFormat, records: &[Record]) -> io::Result<()> {
+    if format == ReportFormat::Csv {
+        writeln!(out, "{}", CSV_HEADER)?;
+    }
+
+    for record in records {
+        match format {
+            ReportFormat::Json => writeln!(out, "{}", to_json(record))?,
+            ReportFormat::Csv => writeln!(out, "{}", to_csv(record))?,
+        }
+    }
+
+    out.flush()
+}
+
+fn to_json(record: &Record) -> String {
+    let string_or_null = |s: Option<&String>| s.map(|s| json_string(s)).unwrap_or_else(|| "null".to_string());
+    let nanos_or_null = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_else(|| "null".to_string());
+
+    format!("{{\"year\":{},\"day\":{},\"input\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"total_ns\":{},\"status\":\"{}\",\"error\":{}}}",
+            record.year,
+            record.day,
+            json_string(&record.input),
+            record.part,
+            string_or_null(record.answer.as_ref()),
+            nanos_or_null(record.elapsed),
+            nanos_or_null(record.total),
+            record.status_name(),
+            string_or_null(record.error()))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+
+    escaped.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+
+    escaped
+}
+
+fn to_csv(record: &Record) -> String {
+    let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+
+    [
+        record.year.to_string(),
+        record.day.to_string(),
+        csv_field(&record.input),
+        record.part.to_string(),
+        record.answer.as_deref().map(csv_field).unwrap_or_default(),
+        nanos(record.elapsed),
+        nanos(record.total),
+        record.status_name().to_string(),
+        record.error().map(|e| csv_field(e)).unwrap_or_default(),
+    ].join(",")
+}
+
+// Quotes a field if required, according to RFC 4180
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", s.replace('"', "\"\""));
+    }
+
+    s.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::puzzle::Part;
+    use crate::report::{csv_field, json_string, write_report, Record, ReportFormat, Status};
+
+    fn records() -> Vec<Record> {
+        let record = |part, answer: Option<&str>, elapsed: Option<u64>, total: Option<u64>, status| Record {
+            year: 2022,
+            day: 5,
+            input: "input_full".to_string(),
+            part,
+            answer: answer.map(str::to_string),
+            elapsed: elapsed.map(Duration::from_nanos),
+            total: total.map(Duration::from_nanos),
+            status,
+        };
+
+        vec![
+            record(Part::One, Some("CMZ"), Some(1500), Some(4000), Status::Ok),
+            record(Part::Two, None, None, None, Status::Failed("Solver panicked: index out of bounds, \"stack\" 3".to_string())),
+            record(Part::One, None, None, Some(2000000000), Status::TimedOut("Timed out after 2.00s".to_string())),
+        ]
+    }
+
+    fn report(format: ReportFormat) -> String {
+        let mut out = Vec::<u8>::new();
+
+        write_report(&mut out, format, &records()).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("C:\\inputs"), "\"C:\\\\inputs\"");
+        assert_eq!(json_string("one\ntwo\r\tthree"), "\"one\\ntwo\\r\\tthree\"");
+        assert_eq!(json_string("bell\u{7}, escape\u{1b}"), "\"bell\\u0007, escape\\u001b\"");
+        assert_eq!(json_string("µs"), "\"µs\"");
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("one,two"), "\"one,two\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("one\ntwo"), "\"one\ntwo\"");
+        assert_eq!(csv_field("one\rtwo"), "\"one\rtwo\"");
+    }
+
+    #[test]
+    fn writes_json_lines() {
+        assert_eq!(report(ReportFormat::Json), concat!(
+            "{\"year\":2022,\"day\":5,\"input\":\"input_full\",\"part\":1,\"answer\":\"CMZ\",\"elapsed_ns\":1500,\"total_ns\":4000,\"status\":\"ok\",\"error\":null}\n",
+            "{\"year\":2022,\"day\":5,\"input\":\"input_full\",\"part\":2,\"answer\":null,\"elapsed_ns\":null,\"total_ns\":null,\"status\":\"failed\",",
+            "\"error\":\"Solver panicked: index out of bounds, \\\"stack\\\" 3\"}\n",
+            "{\"year\":2022,\"day\":5,\"input\":\"input_full\",\"part\":1,\"answer\":null,\"elapsed_ns\":null,\"total_ns\":2000000000,\"status\":\"timed_out\",",
+            "\"error\":\"Timed out after 2.00s\"}\n",
+        ));
+    }
+
+    #[test]
+    fn writes_csv() {
+        assert_eq!(report(ReportFormat::Csv), concat!(
+            "year,day,input,part,answer,elapsed_ns,total_ns,status,error\n",
+            "2022,5,input_full,1,CMZ,1500,4000,ok,\n",
+            "2022,5,input_full,2,,,,failed,\"Solver panicked: index out of bounds, \"\"stack\"\" 3\"\n",
+            "2022,5,input_full,1,,,2000000000,timed_out,Timed out after 2.00s\n",
+        ));
+    }
+}
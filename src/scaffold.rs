@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::puzzle::DayId;
+
+// The module of a new day. Its `parse` just collects the lines and neither part is answered yet, so
+// the day shows up as unimplemented until the parts are filled in (and added to `PARTS`).
+const MODULE_TEMPLATE: &str = "\
+use crate::error::PuzzleResult;
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Part, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const TITLE: &'static str = {title};
+    const PARTS: &'static [Part] = &[];
+
+    type Parsed = Vec<String>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        puzzle::read_all_lines(lines)
+    }
+
+    fn part1(_lines: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(None)
+    }
+
+    fn part2(_lines: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(None)
+    }
+}
+";
+
+const INPUT_FILES: [&str; 2] = ["input_test", "input_full"];
+
+// Creates the module of a new day below `src_dir` and its (empty) inputs below `inputs_dir`. The
+// build script picks up the module on the next build, so there is nothing to register by hand.
+//
+// Returns the files created.
+pub fn create_day(src_dir: &Path, inputs_dir: &Path, day: DayId, title: &str) -> Result<Vec<PathBuf>, String> {
+    let dir = src_dir.join(format!("year{}", day.year)).join(format!("day{:0>2}", day.day));
+    let input_dir = inputs_dir.join(day.year.to_string()).join(format!("day{:0>2}", day.day));
+    let module = dir.join("mod.rs");
+
+    if module.exists() {
+        return Err(format!("'{}' already exists", module.display()));
+    }
+
+    for dir in [&dir, &input_dir] {
+        fs::create_dir_all(dir).map_err(|err| format!("Failed to create '{}': {}", dir.display(), err))?;
+    }
+
+    let mut created = Vec::<PathBuf>::new();
+
+    // The title's debug representation doubles as a string literal
+    let content = MODULE_TEMPLATE.replace("{day}", &format!("{:0>2}", day.day)).replace("{title}", &format!("{:?}", title));
+
+    write_new_file(&module, &content)?;
+    created.push(module);
+
+    for input in INPUT_FILES {
+        let path = input_dir.join(input);
+
+        // Inputs may have been downloaded before the module was created
+        if !path.exists() {
+            write_new_file(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+fn write_new_file(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("Failed to write '{}': {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::DayId;
+    use crate::scaffold;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn creates_new_day() {
+        let src_dir = TempDir::new("scaffold");
+        let inputs_dir = src_dir.join("inputs");
+        let created = scaffold::create_day(&src_dir, &inputs_dir, DayId::new(2022, 3), "Rucksack \"Reorganization\"");
+        let module = std::fs::read_to_string(src_dir.join("year2022/day03/mod.rs"));
+        let input_exists = inputs_dir.join("2022/day03/input_test").is_file();
+
+        let again = scaffold::create_day(&src_dir, &inputs_dir, DayId::new(2022, 3), "Rucksack Reorganization");
+
+        assert_eq!(created.unwrap().len(), 3);
+        assert!(module.unwrap().contains("const TITLE: &'static str = \"Rucksack \\\"Reorganization\\\"\";"));
+        assert!(input_exists);
+        assert_eq!(again, Err(format!("'{}' already exists", src_dir.join("year2022/day03/mod.rs").display())));
+    }
+}
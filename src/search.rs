@@ -0,0 +1,177 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// Searches through the states of a puzzle, e.g. the positions on a map or the positions along with
+// the time they are reached at. A search only needs the start, a function yielding the neighbours
+// of a state (along with the cost of moving there, unless each move costs the same) and a function
+// telling whether a state is a goal:
+//
+//   let path = search::bfs(start, |&p| grid.neighbours(p.x, p.y).map(Point::from), |&p| p == end);
+//
+// The searches stop at the first goal they reach, which is always the cheapest one to reach.
+
+// The states from the start up to and including the goal, and what it costs to get there
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+// Breadth-first search, for when every move costs the same (the cost is the number of moves)
+pub fn bfs<S, N, I, G>(start: S, mut neighbours: N, mut is_goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut parents = HashMap::<S, Option<S>>::new();
+    let mut queue = VecDeque::<S>::new();
+
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let states = path_to(state, &parents);
+
+            return Some(Path { cost: states.len() - 1, states });
+        }
+
+        for next in neighbours(&state) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+// Every state reachable from the start, along with the fewest moves it takes to get there
+pub fn reachable<S, N, I>(start: S, mut neighbours: N) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut moves = HashMap::<S, usize>::new();
+    let mut queue = VecDeque::<(S, usize)>::new();
+
+    moves.insert(start.clone(), 0);
+    queue.push_back((start, 0));
+
+    while let Some((state, n)) = queue.pop_front() {
+        for next in neighbours(&state) {
+            if !moves.contains_key(&next) {
+                moves.insert(next.clone(), n + 1);
+                queue.push_back((next, n + 1));
+            }
+        }
+    }
+
+    moves
+}
+
+// A*, for moves of different costs, the neighbours come with the cost of moving to them. It is
+// Dijkstra's algorithm trying the states that look closer to a goal first. The heuristic estimates
+// the cost from a state to the closest goal, it must never overestimate it (like the Manhattan
+// distance on a map), or the path found might not be the cheapest one. Without a good estimate at
+// hand, `|_| 0` makes it plain Dijkstra.
+pub fn astar<S, N, I, H, G>(start: S, mut neighbours: N, mut heuristic: H, mut is_goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut costs = HashMap::<S, usize>::new();
+    let mut parents = HashMap::<S, Option<S>>::new();
+
+    // The heap orders the states queued by their estimated total cost, the states themselves are
+    // kept apart as they need not be ordered
+    let mut queued = Vec::<S>::new();
+    let mut open = BinaryHeap::<Reverse<(usize, usize, usize)>>::new();
+
+    costs.insert(start.clone(), 0);
+    parents.insert(start.clone(), None);
+    open.push(Reverse((heuristic(&start), 0, 0)));
+    queued.push(start);
+
+    while let Some(Reverse((_, cost, i))) = open.pop() {
+        let state = queued[i].clone();
+
+        // The state has been reached at a lower cost since it was queued
+        if costs[&state] < cost {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some(Path { cost, states: path_to(state, &parents) });
+        }
+
+        for (next, step_cost) in neighbours(&state) {
+            let next_cost = cost + step_cost;
+
+            if costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), Some(state.clone()));
+            open.push(Reverse((next_cost + heuristic(&next), next_cost, queued.len())));
+            queued.push(next);
+        }
+    }
+
+    None
+}
+
+fn path_to<S: Clone + Eq + Hash>(goal: S, parents: &HashMap<S, Option<S>>) -> Vec<S> {
+    let mut states = vec![goal];
+
+    while let Some(Some(parent)) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+
+    states.reverse();
+
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+    use crate::search::{self, Path};
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // The direct road from 'a' to 'd' is the longest one
+        let roads = [('a', 'b', 1), ('b', 'c', 2), ('a', 'c', 5), ('c', 'd', 1), ('a', 'd', 10)];
+        let neighbours = |&from: &char| roads.iter().filter(move |road| road.0 == from).map(|road| (road.1, road.2));
+
+        let cheapest = Some(Path { cost: 4, states: vec!['a', 'b', 'c', 'd'] });
+
+        assert_eq!(search::astar('a', neighbours, |_| 0, |&at| at == 'd'), cheapest);
+        assert_eq!(search::astar('a', neighbours, |&at| if at == 'd' { 0 } else { 1 }, |&at| at == 'd'), cheapest);
+        assert_eq!(search::astar('d', neighbours, |_| 0, |&at| at == 'a'), None);
+        assert_eq!(search::bfs('a', |&from| neighbours(&from).map(|(to, _)| to), |&at| at == 'd').map(|path| path.cost), Some(1));
+
+        // Walking around a wall on a 3x3 map
+        let wall = [Point::new(0, 1), Point::new(1, 1)];
+        let on_map = |p: &Point| p.x >= 0 && p.y >= 0 && p.x < 3 && p.y < 3 && !wall.contains(p);
+        let walk = |p: &Point| p.neighbours().into_iter().filter(on_map);
+
+        let path = search::bfs(Point::ORIGIN, walk, |&p| p == Point::new(0, 2)).unwrap();
+        let reachable = search::reachable(Point::ORIGIN, walk);
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.first(), Some(&Point::ORIGIN));
+        assert_eq!(path.states[3], Point::new(2, 1));
+        assert_eq!(reachable.len(), 7);
+        assert_eq!(reachable[&Point::new(0, 2)], 6);
+    }
+}
@@ -0,0 +1,100 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// A local stand-in for the Advent of Code site, so the client can be tested offline. It serves a
+// single input for any day and judges answers against a single correct (numeric) answer, just like
+// the actual site words it.
+pub struct StandIn {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl StandIn {
+    pub fn start(session: &str, input: &str, correct: i64) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to start stand-in server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::<String>::new()));
+
+        let (session, input, seen) = (format!("session={}", session), input.to_string(), requests.clone());
+
+        // Serves until the test process ends
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Some((request, response)) = handle(stream, &session, &input, correct) {
+                    seen.lock().unwrap().push(format!("{} -> {}", request, response));
+                }
+            }
+        });
+
+        StandIn { url, requests }
+    }
+
+    // The requests handled so far, like "GET /2022/day/1/input -> 200"
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle(stream: TcpStream, session: &str, input: &str, correct: i64) -> Option<(String, u16)> {
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+    let mut request_line = String::new();
+    let mut content_length = 0;
+    let mut has_session = false;
+
+    reader.read_line(&mut request_line).ok()?;
+
+    loop {
+        let mut header = String::new();
+
+        reader.read_line(&mut header).ok()?;
+
+        let header = header.trim();
+
+        if header.is_empty() {
+            break;
+        }
+
+        let (name, value) = header.split_once(':')?;
+
+        match name.to_lowercase().as_str() {
+            "content-length" => content_length = value.trim().parse().ok()?,
+            "cookie" => has_session = value.split(';').any(|c| c.trim() == session),
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+
+    reader.read_exact(&mut body).ok()?;
+
+    let request = request_line.split_whitespace().take(2).collect::<Vec<&str>>().join(" ");
+
+    let (status, page) = match (request.split_once(' ')?, has_session) {
+        (_, false) => (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()),
+        (("GET", path), _) if path.ends_with("/input") => (200, input.to_string()),
+        (("POST", path), _) if path.ends_with("/answer") => (200, judge(&String::from_utf8_lossy(&body), correct)),
+        _ => (404, "404 Not Found".to_string()),
+    };
+
+    let mut stream = stream;
+
+    write!(stream, "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, page.len(), page).ok()?;
+
+    Some((request, status))
+}
+
+fn judge(form: &str, correct: i64) -> String {
+    let answer = form.split('&').find_map(|field| field.strip_prefix("answer=")).and_then(|a| a.parse::<i64>().ok());
+
+    let message = match answer {
+        Some(answer) if answer == correct => "That's the right answer!  You are one gold star closer to saving the jungle.".to_string(),
+        Some(answer) => format!(
+            "That's not the right answer; your answer is too {}.  Please wait one minute before trying again.",
+            if answer > correct { "high" } else { "low" }),
+        None => "That's not the right answer.  Please wait one minute before trying again.".to_string(),
+    };
+
+    format!("<html><body><main><article><p>{}</p></article></main></body></html>", message)
+}
@@ -0,0 +1,41 @@
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+// A fresh directory below the system's temporary directory, removed again when dropped, so a
+// failing test does not leave it behind for the next run
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // `name` tells the tests' directories apart, e.g. "scaffold"
+    pub fn new(name: &str) -> TempDir {
+        let n = CREATED.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("aoc04-{}-{}-{}", name, std::process::id(), n));
+
+        // Left over from a run that was killed before it could clean up
+        let _ = fs::remove_dir_all(&path);
+
+        fs::create_dir_all(&path).expect("Failed to create temporary directory");
+
+        TempDir { path }
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
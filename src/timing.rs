@@ -0,0 +1,99 @@
+use std::cell::Cell;
+use std::fs;
+use std::time::Duration;
+
+// How long a single run of a solution took. A part is `None` if it was not run.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StageTimings {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+    pub total: Duration,
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+
+    if nanos < 1_000 {
+        return format!("{}ns", nanos);
+    }
+
+    if nanos < 1_000_000 {
+        return format!("{:.1}µs", nanos as f64 / 1_000.0);
+    }
+
+    if nanos < 1_000_000_000 {
+        return format!("{:.2}ms", nanos as f64 / 1_000_000.0);
+    }
+
+    format!("{:.2}s", d.as_secs_f64())
+}
+
+thread_local! {
+    static CHARGED_CPU_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+// The kernel's clock ticks per second (`USER_HZ`), which /proc/[pid]/stat counts in. It is 100 on
+// any architecture worth mentioning.
+const CLOCK_TICKS_PER_SEC: u64 = 100;
+
+// The CPU time of the current thread in nanoseconds, if the kernel keeps the scheduler's
+// statistics. Without them it reads 0, and so it does for a thread that has hardly run yet.
+fn schedstat_cpu_time() -> Option<Duration> {
+    let stats = fs::read_to_string("/proc/thread-self/schedstat").ok()?;
+    let nanos = stats.split_whitespace().next()?.parse::<u64>().ok()?;
+
+    Some(Duration::from_nanos(nanos))
+}
+
+// The user and system time of the current thread, only in clock ticks though
+fn stat_cpu_time() -> Option<Duration> {
+    let stat = fs::read_to_string("/proc/thread-self/stat").ok()?;
+
+    // The thread's name comes in parentheses and may contain anything, even spaces and
+    // parentheses. After it, utime and stime are the 12th and 13th field.
+    let (_, fields) = stat.rsplit_once(')')?;
+    let mut fields = fields.split_whitespace().skip(11).map(|f| f.parse::<u64>().ok());
+    let ticks = fields.next()?? + fields.next()??;
+
+    Some(Duration::from_nanos(ticks * 1_000_000_000 / CLOCK_TICKS_PER_SEC))
+}
+
+// Measures the CPU time the current thread uses from now on, including what is charged to it by
+// `charge_cpu_time`. Only available on Linux.
+pub struct CpuTimer {
+    schedstat: Option<Duration>,
+    stat: Option<Duration>,
+    charged: Duration,
+}
+
+impl CpuTimer {
+    pub fn start() -> CpuTimer {
+        CpuTimer { schedstat: schedstat_cpu_time(), stat: stat_cpu_time(), charged: charged_cpu_time() }
+    }
+
+    // `None` if there is no telling
+    pub fn elapsed(&self) -> Option<Duration> {
+        // The scheduler's statistics are kept if they have counted anything by now, otherwise the
+        // clock ticks have to do
+        let own = match (self.schedstat, schedstat_cpu_time()) {
+            (Some(start), Some(end)) if end > Duration::ZERO => end.saturating_sub(start),
+            _ => stat_cpu_time()?.saturating_sub(self.stat?),
+        };
+
+        Some(own + charged_cpu_time().saturating_sub(self.charged))
+    }
+}
+
+fn charged_cpu_time() -> Duration {
+    CHARGED_CPU_TIME.with(|c| c.get())
+}
+
+// Accounts the CPU time of a helper thread to the current thread, which waited for the helper.
+// Nothing to account for if the helper's CPU time is not available, then neither is the current
+// thread's.
+pub fn charge_cpu_time(cpu_time: Option<Duration>) {
+    if let Some(cpu_time) = cpu_time {
+        CHARGED_CPU_TIME.with(|c| c.set(c.get() + cpu_time));
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+// How often the watched directories are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// When and how big each file was, a change to either counts as a change of the file
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+// Rebuilds the runner and runs it with `run_args` whenever a file below one of the directories
+// changes, and once at the start. Only stops when interrupted.
+pub fn watch(dirs: &[PathBuf], run_args: &[String]) -> Result<(), String> {
+    // Cargo replaces the binary when rebuilding it, so its path is taken while it still exists
+    let exe = env::current_exe().map_err(|err| format!("Failed to find the running binary: {}", err))?;
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+    let mut last: Option<Snapshot> = None;
+
+    loop {
+        let snapshot = snapshot(dirs);
+
+        if last.as_ref() != Some(&snapshot) {
+            if last.is_some() {
+                println!("==> Changes detected");
+            }
+
+            // Changes made while building or running are picked up by the next round
+            last = Some(snapshot);
+
+            match rebuild(&manifest) {
+                Ok(()) => run(&exe, run_args),
+                Err(err) => eprintln!("Error: {}", err),
+            }
+
+            let watched: Vec<String> = dirs.iter().map(|dir| dir.display().to_string()).collect();
+
+            println!("==> Watching {} for changes (Ctrl-C to stop)", watched.join(" and "));
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn rebuild(manifest: &Path) -> Result<(), String> {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+
+    cargo.args(["build", "--quiet", "--manifest-path"]).arg(manifest);
+
+    // Rebuild the binary that is running
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+
+    match cargo.status() {
+        Ok(status) if status.success() => Ok(()),
+        Ok(_) => Err("The build failed, waiting for changes".to_string()),
+        Err(err) => Err(format!("Failed to run cargo: {}", err)),
+    }
+}
+
+fn run(exe: &Path, args: &[String]) {
+    // Failing puzzles are reported by the run itself
+    if let Err(err) = Command::new(exe).args(args).status() {
+        eprintln!("Error: Failed to run '{}': {}", exe.display(), err);
+    }
+}
+
+// The files below the directories, missing directories just have none
+fn snapshot(dirs: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = dirs.to_vec();
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+
+            match metadata.is_dir() {
+                true => pending.push(entry.path()),
+                false => {
+                    snapshot.insert(entry.path(), (metadata.modified().ok(), metadata.len()));
+                }
+            }
+        }
+    }
+
+    snapshot
+}
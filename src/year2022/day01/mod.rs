@@ -1,8 +1,18 @@
-use std::fs::File;
-use std::io::{BufReader, Lines};
+use crate::error::PuzzleResult;
+use crate::params::Params;
+use crate::puzzle::{Input, Part, Solution};
 
-pub fn solve(_: &mut Lines<BufReader<File>>, _: bool) {
-    todo!()
+pub struct Day01;
+
+impl Solution for Day01 {
+    const TITLE: &'static str = "Calorie Counting";
+    const PARTS: &'static [Part] = &[];
+
+    type Parsed = ();
+
+    fn parse(_: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        todo!()
+    }
 }
 
 // The former logic:
@@ -31,8 +41,8 @@ pub fn solve(_: &mut Lines<BufReader<File>>, _: bool) {
 //     _sums.sort();
 //     _sums.reverse();
 //
-//     println!("Max value {}", _sums.first().unwrap());
-//     println!("Top 3 {}", sum_up_first_n_entries(&_sums, 3))
+//     outln!("Max value {}", _sums.first().unwrap());
+//     outln!("Top 3 {}", sum_up_first_n_entries(&_sums, 3))
 // }
 //
 // fn sum_up_first_n_entries(_v: &Vec<i32>, n: usize) -> i32 {
@@ -1,20 +1,44 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Lines};
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, _: bool) {
-    let mut sum = 0;
+use crate::error::{AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Part, Solution};
 
-    for line in lines {
-        let chars: Vec<char> = line.expect("Failed to read line").chars().collect();
+pub struct Day02;
 
-        let opponent = chars[0];
-        let you = chars[2];
+impl Solution for Day02 {
+    const TITLE: &'static str = "Rock Paper Scissors";
+    const PARTS: &'static [Part] = &[Part::Two];
 
-        sum += play_round(opponent, you);
+    // The opponent's shape and the desired outcome of each round
+    type Parsed = Vec<(char, char)>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut rounds = Vec::<(char, char)>::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            rounds.push(parse_round(&line).at_line(i + 1, &line)?);
+        }
+
+        Ok(rounds)
     }
 
-    println!("\tSum: {}", sum)
+    fn part2(rounds: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let sum: i32 = rounds.iter().map(|(opponent, you)| play_round(*opponent, *you)).sum();
+
+        Ok(Some(sum.into()))
+    }
+}
+
+fn parse_round(line: &str) -> PuzzleResult<(char, char)> {
+    let chars: Vec<char> = line.chars().collect();
+
+    match chars[..] {
+        [opponent @ 'A'..='C', ' ', you @ 'X'..='Z'] => Ok((opponent, you)),
+        _ => Err(PuzzleError::new("Expected a round like 'A Y'")),
+    }
 }
 
 
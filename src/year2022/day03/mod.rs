@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use crate::error::{AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Part, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const PARTS: &'static [Part] = &[Part::Two];
+
+    // The groups of (up to) three elves, along with the number of each group's first line
+    type Parsed = Vec<(usize, Vec<String>)>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut groups = Vec::<(usize, Vec<String>)>::new();
+
+        loop {
+            let grouped_lines = puzzle::read_n_lines(lines, 3)?;
+
+            if grouped_lines.is_empty() {
+                break
+            }
+
+            let first_line = lines.line_number() + 1 - grouped_lines.len();
+            let is_last = grouped_lines.len() < 3;
+
+            groups.push((first_line, grouped_lines));
+
+            if is_last {
+                break
+            }
+        }
+
+        Ok(groups)
+    }
+
+    fn part2(groups: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut sum = 0;
+
+        for (first_line, grouped_lines) in groups {
+            // Point errors at the first line of the group
+            let group_id = find_group_id(grouped_lines).at_line(*first_line, &grouped_lines[0])?;
+
+            sum += priority(group_id).at_line(*first_line, &grouped_lines[0])?;
+        }
+
+        Ok(Some(sum.into()))
+    }
+}
+
+fn find_group_id(lines: &Vec<String>) -> PuzzleResult<char> {
+    let mut map = HashMap::<char, i8>::new();
+
+    for line in lines {
+        let mut per_elf_map = HashSet::<char>::new();
+
+        for c in line.chars() {
+            if !per_elf_map.insert(c) {
+                continue;
+            }
+
+            let val = map.get(&c);
+
+            if val.is_some() {
+                if *val.unwrap() == 2 {
+                    return Ok(c);
+                }
+
+                *map.get_mut(&c).unwrap() += 1;
+
+                continue;
+            }
+
+            map.insert(c, 1);
+        }
+    }
+
+    Err(PuzzleError::new("Cannot find group id"))
+}
+
+// fn find_misplaced(line: String) -> char {
+//     let mut compartment1 = HashSet::<char>::new();
+//
+//     let half = line.len() / 2;
+//     let mut i = 0;
+//
+//     for c in line.chars() {
+//         if i < half {
+//             compartment1.insert(c);
+//         } else {
+//             if compartment1.contains(&c) {
+//                 return c
+//             }
+//         }
+//
+//         i += 1;
+//     }
+//
+//     panic!("No duplicate found")
+// }
+
+fn priority(c: char) -> PuzzleResult<i32> {
+    let i = c as u32;
+
+    if i >= 97 && i <= 122 {
+        return Ok((i - 96).try_into().unwrap());
+    }
+
+    if i >= 65 && i <= 90 {
+        return Ok((i - 65 + 27).try_into().unwrap());
+    }
+
+    Err(PuzzleError::new(format!("Invalid item '{}'", c)))
+}
\ No newline at end of file
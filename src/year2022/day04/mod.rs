@@ -0,0 +1,46 @@
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::interval::Interval;
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Part, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const TITLE: &'static str = "Camp Cleanup";
+    const PARTS: &'static [Part] = &[Part::Two];
+
+    type Parsed = Vec<(Interval, Interval)>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut pairs = Vec::<(Interval, Interval)>::new();
+
+        for (i, line) in lines.enumerate() {
+            let s = line?;
+
+            pairs.push(parse_pair(&s).at_line(i + 1, &s)?);
+        }
+
+        Ok(pairs)
+    }
+
+    fn part2(pairs: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let overlapping = pairs.iter().filter(|(r1, r2)| r1.overlaps(r2)).count();
+
+        Ok(Some(overlapping.into()))
+    }
+}
+
+fn parse_pair(s: &str) -> PuzzleResult<(Interval, Interval)> {
+    let (first, second) = s.split_once(",").ok_or_else(|| PuzzleError::new("Expected two ranges separated by ','"))?;
+
+    return Ok((parse_range(first)?, parse_range(second)?));
+}
+
+fn parse_range(s: &str) -> PuzzleResult<Interval> {
+    let (start, end) = s.split_once("-").ok_or_else(|| PuzzleError::new(format!("Invalid range '{}'", s)))?;
+
+    let start = error::parse(start, "start of range")?;
+    let end = error::parse(end, "end of range")?;
+
+    return Interval::new(start, end).ok_or_else(|| PuzzleError::new(format!("Invalid range '{}', it ends before it starts", s)));
+}
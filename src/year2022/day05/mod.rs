@@ -0,0 +1,143 @@
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Part, Solution};
+
+pub struct Day05;
+
+// Moving `count` crates from one stack to another, as given in line `line` of the input
+pub struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+    line: usize,
+    text: String,
+}
+
+impl Solution for Day05 {
+    const TITLE: &'static str = "Supply Stacks";
+    const PARTS: &'static [Part] = &[Part::Two];
+
+    type Parsed = (Vec<Vec<char>>, Vec<Instruction>);
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut stacks = Vec::<Vec<char>>::new();
+
+        // Test data
+        // stacks.push(vec!['Z', 'N']);
+        // stacks.push(vec!['M', 'C', 'D']);
+        // stacks.push(vec!['P']);
+
+        // Full data
+        stacks.push(vec!['J', 'H', 'P', 'M', 'S', 'F', 'N', 'V']);
+        stacks.push(vec!['S', 'R', 'L', 'M', 'J', 'D', 'Q']);
+        stacks.push(vec!['N', 'Q', 'D', 'H', 'C', 'S', 'W', 'B']);
+        stacks.push(vec!['R', 'S', 'C', 'L']);
+        stacks.push(vec!['M', 'V', 'T', 'P', 'F', 'B']);
+        stacks.push(vec!['T', 'R', 'Q', 'N', 'C']);
+        stacks.push(vec!['G', 'V', 'R']);
+        stacks.push(vec!['C', 'Z', 'S', 'P', 'D', 'L', 'R']);
+        stacks.push(vec!['D', 'S', 'J', 'V', 'G', 'P', 'B', 'F']);
+
+        let mut stack_lines = Vec::<String>::new();
+        let mut instructions = Vec::<Instruction>::new();
+
+        let mut in_parse_stack_mode = true;
+
+        for (i, line) in lines.enumerate() {
+            let line= line?;
+            if line == "" {
+                in_parse_stack_mode = false;
+
+                continue;
+            }
+
+            if in_parse_stack_mode {
+                stack_lines.push(line);
+            } else {
+                let (count, from, to) = parse_instruction(&line, stacks.len()).at_line(i + 1, &line)?;
+
+                instructions.push(Instruction { count, from, to, line: i + 1, text: line });
+            }
+        }
+
+        Ok((stacks, instructions))
+    }
+
+    fn part2((stacks, instructions): &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut stacks = stacks.clone();
+
+        for Instruction { count, from, to, line, text } in instructions {
+            let (count, from, to) = (*count, *from, *to);
+
+            debug!("Line: {:?}", text);
+            debug!("Instructions: {} from {} to {}", count, from, to);
+            debug!("From stack ({}): {:?}", stacks[from].len(), stacks[from]);
+
+            if count > stacks[from].len() {
+                return Err(PuzzleError::new(format!("Cannot move {} crates from a stack of {}", count, stacks[from].len())).at_line(*line, text));
+            }
+
+            for j in 0..count {
+                let from_index = stacks[from].len() + j - count;
+                let copy = stacks[from][from_index].to_owned();
+                stacks[to].push(copy);
+                stacks[from].remove(from_index);
+            }
+        }
+
+        let mut stack_heads = String::new();
+
+        for stack in stacks {
+            if let Some(c) = stack.last() {
+                stack_heads.push(*c);
+            }
+        }
+
+        Ok(Some(stack_heads.into()))
+    }
+}
+
+// Parses a line like `move 1 from 2 to 1` into the count and the (zero based) stack indices
+fn parse_instruction(line: &str, stack_count: usize) -> PuzzleResult<(usize, usize, usize)> {
+    let replaced = line.replace("move", "");
+    let trimmed = replaced.trim();
+    let instructions: Vec<&str> = trimmed.split(" ").collect();
+
+    if instructions.len() != 5 || instructions[1] != "from" || instructions[3] != "to" {
+        return Err(PuzzleError::new("Expected an instruction like 'move 1 from 2 to 1'"));
+    }
+
+    let stack = |s: &str| -> PuzzleResult<usize> {
+        match error::parse::<usize>(s, "stack")? {
+            n if n >= 1 && n <= stack_count => Ok(n - 1),
+            _ => Err(PuzzleError::new(format!("There is no stack {}", s))),
+        }
+    };
+
+    let count: usize = error::parse(instructions[0], "count")?;
+
+    return Ok((count, stack(instructions[2])?, stack(instructions[4])?));
+}
+
+// fn parse_stacks(lines: &mut Vec<String>) -> Vec<Vec<char>> {
+//     let mut stacks = Vec::<Vec<char>>::new();
+//
+//     lines.reverse();
+//     lines.remove(0);
+//
+//     for line in lines {
+//         let line = line.replace("[", "").replace("]", "")
+//
+//         let mut i = 0;
+//
+//         for c in line {
+//
+//
+//             i += 1;
+//         }
+//     }
+//
+//     lines.first().expect("Could not get first line")
+//
+//
+// }
\ No newline at end of file
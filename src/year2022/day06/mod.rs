@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use crate::error::PuzzleResult;
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Part, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const TITLE: &'static str = "Tuning Trouble";
+    const PARTS: &'static [Part] = &[Part::Two];
+
+    // Usually there is just a single signal, the test input might contain several though
+    type Parsed = Vec<String>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        puzzle::read_all_lines(lines)
+    }
+
+    fn part2(signals: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let signal_starts: Vec<String> = signals.iter().map(|signal| check_line(signal).to_string()).collect();
+
+        Ok(Some(signal_starts.join(", ").into()))
+    }
+}
+
+fn check_line(line: &String) -> usize {
+    let mut set = Vec::<char>::new();
+
+    let mut i = 0;
+
+    for c in line.chars() {
+        if add(&mut set, c) {
+            return i + 1
+        }
+
+        i += 1;
+    }
+
+    return 0
+}
+
+fn add(v: &mut Vec::<char>, c: char) -> bool {
+    v.push(c);
+
+    if v.len() == 14 + 1 {
+        v.remove(0);
+    } else {
+        return false
+    }
+
+    let mut set = HashSet::<char>::new();
+
+    for i in 0..v.len() {
+        if !set.insert(v[i]) {
+            return false
+        }
+    }
+
+    return true
+}
+
@@ -0,0 +1,99 @@
+use std::collections::{HashMap};
+
+use crate::error::{self, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const TITLE: &'static str = "No Space Left On Device";
+
+    // The total size of each directory, by path
+    type Parsed = HashMap<String, u32>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut dir_map = HashMap::<String, u32>::new();
+
+        // First line is always `$ cd /`, drop it.
+        if lines.next().transpose()?.is_none() {
+            return Err(PuzzleError::new("There is no first line"));
+        }
+
+        process_dir(lines, String::from("/"), &mut dir_map)?;
+
+        Ok(dir_map)
+    }
+
+    fn part1(dir_map: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut total_size: u32 = 0;
+        for (_, size) in dir_map {
+            if size <= &100_000 {
+                total_size += size;
+            }
+        }
+
+        Ok(Some(total_size.into()))
+    }
+
+    fn part2(dir_map: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        const DEVICE_TOTAL_SIZE: u32 = 70_000_000;
+        const SPACE_REQUIRED: u32 = 30_000_000;
+
+        let free_space = DEVICE_TOTAL_SIZE.checked_sub(dir_map["/"]).ok_or_else(|| PuzzleError::new("The files do not fit on the device"))?;
+
+
+        let mut smallest_sufficient_size = 0;
+        for (_, size) in dir_map {
+            if free_space + size >= SPACE_REQUIRED && (size < &smallest_sufficient_size || smallest_sufficient_size == 0) {
+                smallest_sufficient_size = size.clone()
+            }
+        }
+
+        Ok(Some(smallest_sufficient_size.into()))
+    }
+}
+
+fn process_dir(lines: &mut Input, cwd: String, dir_map: &mut HashMap<String, u32>) -> PuzzleResult<u32> {
+    let mut cur_size: u32 = 0;
+
+    loop {
+        let l = match lines.next() {
+            Some(l) => l?,
+            None => break
+        };
+
+        if l.starts_with("$ cd") {
+            let path = l.trim_start_matches("$ cd ");
+
+            if path == ".." {
+                break;
+            }
+
+            let mut new_cwd = cwd.clone();
+            new_cwd.push_str(path);
+            new_cwd.push('/');
+
+            cur_size += process_dir(lines, new_cwd, dir_map)?;
+
+            continue;
+        }
+
+        // We do not need to actually handle check for `$ ls`, nor for `dir` entries
+        if l.starts_with("$") || l.starts_with("dir") {
+            continue;
+        }
+
+        // Must be a file then
+        let file_size: u32 = match l.split_whitespace().next() {
+            None => return Err(PuzzleError::new("File entry has no field").at_line(lines.line_number(), &l)),
+            Some(v) => error::parse(v, "file size").map_err(|err| err.at_line(lines.line_number(), &l))?
+        };
+
+        cur_size += file_size;
+    }
+
+    dir_map.insert(cwd, cur_size);
+
+    return Ok(cur_size)
+}
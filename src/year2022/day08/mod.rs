@@ -0,0 +1,165 @@
+use std::cmp::max;
+
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const TITLE: &'static str = "Treetop Tree House";
+
+    // The height of each tree
+    type Parsed = Grid<u32>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let lines = puzzle::read_all_lines(lines)?;
+
+        if lines.is_empty() {
+            return Err(PuzzleError::new("There are no trees"));
+        }
+
+        let area = Grid::parse(&lines, |c| {
+            c.to_digit(10).ok_or_else(|| PuzzleError::new(format!("Invalid tree height '{}'", c)))
+        })?;
+
+        // The area has to be square, that's what the walks through it rely on
+        if area.width() != area.height() {
+            return Err(PuzzleError::new(format!("Expected {} trees per row", area.height())).at_line(1, &lines[0]));
+        }
+
+        Ok(area)
+    }
+
+    fn part1(area: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let grid_size = area.width();
+        let mut visible_trees = 0;
+
+        // The trees at the edge are always visible
+        for row_idx in 1..grid_size - 1 {
+            for col_idx in 1..grid_size - 1 {
+                if visible_in_row(area, row_idx, col_idx) || visible_in_column(area, col_idx, row_idx) {
+                    visible_trees += 1;
+                }
+            }
+        }
+
+        Ok(Some((visible_trees + grid_size * 4 - 4).into()))
+    }
+
+    fn part2(area: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let grid_size = area.width();
+        let mut max_scenic_score = 0;
+
+        // The trees at the edge will always have a score of 0, so we do not even have to consider them
+        for row_idx in 1..grid_size - 1 {
+            for col_idx in 1..grid_size - 1 {
+                let cur_score = score(area, row_idx, col_idx);
+
+                max_scenic_score = max(cur_score, max_scenic_score);
+            }
+        }
+
+        Ok(Some(max_scenic_score.into()))
+    }
+}
+
+fn score(area: &Grid<u32>, row_idx: usize, column_idx: usize) -> u32 {
+    let tree_height = area[(column_idx as i64, row_idx as i64)];
+
+    let up = vec_up(area, row_idx, column_idx);
+    let down = vec_down(area, row_idx, column_idx);
+    let left = vec_left(area, row_idx, column_idx);
+    let right = vec_right(area, row_idx, column_idx);
+
+    viewing_distance(&up, tree_height) *
+        viewing_distance(&down, tree_height) *
+        viewing_distance(&left, tree_height) *
+        viewing_distance(&right, tree_height)
+}
+
+// The trees seen when looking up from a tree, the closest one first
+fn vec_up(area: &Grid<u32>, row_idx: usize, column_idx: usize) -> Vec<u32> {
+    let mut vec: Vec<u32> = area.column(column_idx as i64).take(row_idx).copied().collect();
+
+    vec.reverse();
+
+    vec
+}
+
+fn vec_down(area: &Grid<u32>, row_idx: usize, column_idx: usize) -> Vec<u32> {
+    area.column(column_idx as i64).skip(row_idx + 1).copied().collect()
+}
+
+fn vec_left(area: &Grid<u32>, row_idx: usize, column_idx: usize) -> Vec<u32> {
+    let row = area.row(row_idx as i64).expect("row not in area");
+
+    row[..column_idx].iter().rev().copied().collect()
+}
+
+fn vec_right(area: &Grid<u32>, row_idx: usize, column_idx: usize) -> Vec<u32> {
+    let row = area.row(row_idx as i64).expect("row not in area");
+
+    row[column_idx + 1..].to_vec()
+}
+
+fn viewing_distance(vec: &Vec<u32>, tree_height: u32) -> u32 {
+    for (i, other_tree_height) in vec.iter().enumerate() {
+        if other_tree_height >= &tree_height {
+            return (i + 1) as u32;
+        }
+    }
+
+    return vec.len() as u32;
+}
+
+fn visible_in_row(area: &Grid<u32>, row_idx: usize, tree_index: usize) -> bool {
+    let row = area.row(row_idx as i64).expect("row not in area");
+
+    visible_in_vec(&row.to_vec(), tree_index)
+}
+
+fn visible_in_column(area: &Grid<u32>, column_idx: usize, tree_index: usize) -> bool {
+    let column: Vec<u32> = area.column(column_idx as i64).copied().collect();
+
+    visible_in_vec(&column, tree_index)
+}
+
+fn visible_in_vec(vec: &Vec<u32>, tree_idx: usize) -> bool {
+    let tree_size = vec[tree_idx];
+
+    let mut visible = true;
+
+    for (i, tree) in vec.iter().enumerate() {
+        if i == tree_idx {
+            break;
+        }
+
+        if tree >= &tree_size {
+            visible = false
+        }
+    }
+
+    if visible {
+        return true;
+    }
+
+    let mut row = vec.clone();
+    row.reverse();
+
+    let mut visible = true;
+    let reversed_tree_idx = row.len() - 1 - tree_idx;
+
+    for (i, tree) in row.iter().enumerate() {
+        if i == reversed_tree_idx {
+            break;
+        }
+
+        if tree >= &tree_size {
+            visible = false
+        }
+    }
+
+    return visible;
+}
@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::point::{Direction, Point};
+use crate::puzzle::{Answer, Input, Solution};
+
+fn parse_move(line: &str) -> PuzzleResult<Vec<Direction>> {
+    let splits = line.split_whitespace().collect::<Vec<&str>>();
+
+    let direction = match *splits.get(0).ok_or_else(|| PuzzleError::new("No direction given in line"))? {
+        "R" => Direction::Right,
+        "L" => Direction::Left,
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        other => return Err(PuzzleError::new(format!("Invalid direction '{}'", other))),
+    };
+
+    let count: i32 = error::parse(splits.get(1).ok_or_else(|| PuzzleError::new("No count given in line"))?, "count")?;
+
+    let mut moves = Vec::<Direction>::new();
+
+    for _ in 0..count {
+        moves.push(direction);
+    }
+
+    return Ok(moves);
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const TITLE: &'static str = "Rope Bridge";
+
+    // The head's moves, one step each
+    type Parsed = Vec<Direction>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut moves = Vec::<Direction>::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            moves.append(&mut parse_move(&line).at_line(i + 1, &line)?);
+        }
+
+        Ok(moves)
+    }
+
+    fn part1(moves: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(solve1(moves).into()))
+    }
+
+    fn part2(moves: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(solve2(moves).into()))
+    }
+}
+
+fn solve1(moves: &Vec<Direction>) -> usize {
+    let mut visited_coords = HashSet::<Point>::new();
+
+    // We assume starting at (0, 0).
+    // Doesn't really matter for the puzzle as we are just interesting in the sum of visited coords,
+    // not in their actual position.
+    // Therefore, we also do not need an actual map of the area.
+
+    let mut cur_head_coord = Point::ORIGIN;
+    let mut cur_tail_coord = Point::ORIGIN;
+
+    visited_coords.insert(cur_tail_coord);
+
+    for m in moves {
+        cur_head_coord = cur_head_coord.step(*m);
+
+        move_tail_according_to_head(&mut cur_tail_coord, cur_head_coord);
+
+        visited_coords.insert(cur_tail_coord);
+    }
+
+    return visited_coords.len();
+}
+
+fn solve2(moves: &Vec<Direction>) -> usize {
+    let mut visited_coords = HashSet::<Point>::new();
+
+    let mut knots = Vec::<Point>::new();
+
+    const TAIL: usize = 0;
+    const HEAD: usize = 9;
+
+    for _ in 0..HEAD+1 {
+        knots.push(Point::ORIGIN)
+    }
+
+    visited_coords.insert(knots[TAIL]);
+
+    for m in moves {
+        let head = knots.get_mut(HEAD).unwrap();
+        *head = head.step(*m);
+
+        for i in 0..HEAD {
+            let head = *knots.get(HEAD-i).unwrap();
+            let knot = knots.get_mut(HEAD-i-1).unwrap();
+
+            move_tail_according_to_head(knot, head);
+        }
+
+        visited_coords.insert(knots[TAIL]);
+    }
+
+    return visited_coords.len();
+}
+
+fn move_tail_according_to_head(tail: &mut Point, head: Point) {
+    if tail.chebyshev_distance(head) <= 1 {
+        return;
+    }
+
+    // One step towards the head, diagonally unless they are in the same row or column
+    *tail += (head - *tail).signum();
+}
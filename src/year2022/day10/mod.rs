@@ -0,0 +1,93 @@
+use crate::error::{self, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    // The instructions, `noop` being `None` and `addx` the value to add
+    type Parsed = Vec<Option<i32>>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut instructions = Vec::<Option<i32>>::new();
+
+        for (i, line) in lines.enumerate() {
+            let s = line?;
+            let splits: Vec<&str> = s.split_whitespace().collect();
+
+            let inc: Option<i32> = match splits[..] {
+                ["noop"] => None,
+                ["addx", inc] => Some(error::parse(inc, "increment").map_err(|err| err.at_line(i + 1, &s))?),
+                _ => return Err(PuzzleError::new("Expected 'noop' or 'addx <increment>'").at_line(i + 1, &s)),
+            };
+
+            instructions.push(inc);
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let (sum_signal_strengths, _) = run(instructions);
+
+        Ok(Some(sum_signal_strengths.into()))
+    }
+
+    fn part2(instructions: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let (_, screen) = run(instructions);
+
+        Ok(Some(screen.into()))
+    }
+}
+
+// Runs the program, returns the sum of the signal strengths and what is drawn on the screen
+fn run(instructions: &Vec<Option<i32>>) -> (i32, String) {
+    let mut signal_strengths = Vec::<i32>::new();
+    let mut sum_signal_strengths = 0;
+
+    let mut screen = String::from("");
+
+    let mut cycle = 1;
+    let mut reg_x = 1;
+
+    let mut check = |cycle: i32, reg_x: i32| {
+        if cycle % 40 == 20 {
+            signal_strengths.push(reg_x * cycle);
+
+            sum_signal_strengths += reg_x * cycle;
+        }
+
+        let cur_pixel = (cycle - 1) % 40;
+
+        if reg_x + 1 == cur_pixel || reg_x == cur_pixel || reg_x - 1 == cur_pixel {
+            screen.push('#');
+        } else {
+            screen.push('.');
+        }
+
+        if cycle % 40 == 0 {
+            screen.push('\n');
+        }
+    };
+
+    for inc in instructions {
+        check(cycle, reg_x);
+
+        if let Some(inc) = inc {
+            cycle += 1;
+
+            check(cycle, reg_x);
+
+            reg_x += inc;
+        }
+
+        cycle += 1;
+    }
+
+    debug!("Signal strengths: {:?}", signal_strengths);
+    debug!("Cycles done: {}", cycle);
+
+    (sum_signal_strengths, screen)
+}
\ No newline at end of file
@@ -0,0 +1,208 @@
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Solution};
+
+// How inspecting an item changes the worry level
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(i64),
+    Multiply(i64),
+    Square,
+}
+
+impl Operation {
+    // The right-hand side of "new = old * 19", "new = old + 6", "new = old * old", ...
+    fn from_text(s: &str) -> PuzzleResult<Operation> {
+        match s.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["old", "*", "old"] => Ok(Operation::Square),
+            ["old", "+", "old"] => Ok(Operation::Multiply(2)),
+            ["old", "*", n] => Ok(Operation::Multiply(error::parse(n, "factor")?)),
+            ["old", "+", n] => Ok(Operation::Add(error::parse(n, "summand")?)),
+            _ => Err(PuzzleError::new(format!("Invalid operation '{}'", s.trim()))),
+        }
+    }
+
+    fn apply(&self, worry_level: i64) -> i64 {
+        match self {
+            Operation::Add(n) => worry_level + n,
+            Operation::Multiply(n) => worry_level * n,
+            Operation::Square => worry_level * worry_level,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items_stack: Vec<i64>,
+    inspections_performed: i64,
+    divisible_by: i64,
+    throw_targets: [usize; 2],
+    operation: Operation,
+}
+
+// The i-th line of a monkey's description, which starts with `prefix` (after the indentation)
+struct Field<'a> {
+    lines: &'a [String],
+    i: usize,
+    first_line: usize,
+    prefix: &'static str,
+}
+
+impl Field<'_> {
+    // Parses the rest of the line after the prefix, errors point at the line
+    fn parse<T, F: FnOnce(&str) -> PuzzleResult<T>>(&self, parse: F) -> PuzzleResult<T> {
+        let line = self.first_line + self.i;
+
+        let text = self.lines.get(self.i)
+            .ok_or_else(|| PuzzleError::new(format!("Expected '{}' after line {}", self.prefix, line - 1)))?;
+
+        text.trim().strip_prefix(self.prefix)
+            .ok_or_else(|| PuzzleError::new(format!("Expected '{}'", self.prefix)))
+            .and_then(parse)
+            .at_line(line, text)
+    }
+}
+
+impl Monkey {
+    // The six lines describing the monkey, `first_line` being the number of the first one
+    fn from_text(lines: &[String], first_line: usize) -> PuzzleResult<Monkey> {
+        let field = |i: usize, prefix: &'static str| Field { lines, i, first_line, prefix };
+
+        let items_stack = field(1, "Starting items:").parse(|items| {
+            items.split(',').map(|item| error::parse(item, "worry level")).collect()
+        })?;
+
+        let operation = field(2, "Operation: new =").parse(Operation::from_text)?;
+
+        let divisible_by = field(3, "Test: divisible by").parse(|n| match error::parse::<i64>(n, "divisor")? {
+            divisor if divisor > 0 => Ok(divisor),
+            _ => Err(PuzzleError::new("The divisor must be positive")),
+        })?;
+
+        let if_true = field(4, "If true: throw to monkey").parse(|n| error::parse(n, "monkey"))?;
+        let if_false = field(5, "If false: throw to monkey").parse(|n| error::parse(n, "monkey"))?;
+
+        Ok(Monkey {
+            items_stack,
+            inspections_performed: 0,
+            divisible_by,
+            throw_targets: [if_true, if_false],
+            operation,
+        })
+    }
+
+    fn add_item(&mut self, worry_value: i64) {
+        self.items_stack.push(worry_value);
+    }
+
+    // returns the index/name of the next monkey
+    fn throw_to(&self, worry_level: i64) -> usize {
+        if worry_level % self.divisible_by == 0 {
+            return self.throw_targets[0];
+        }
+
+        return self.throw_targets[1];
+    }
+
+    fn inspect(&self, mut worry_value: i64, use_modulo: bool, lcd: i64) -> i64 {
+        worry_value = self.operation.apply(worry_value);
+
+        if use_modulo {
+            worry_value %= lcd;
+        }
+
+        return worry_value;
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Parsed = Vec<Monkey>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let lines = puzzle::read_all_lines(lines)?;
+        let mut monkeys = Vec::<Monkey>::new();
+
+        // Each monkey takes six lines, followed by an empty one unless it is the last monkey
+        for (i, block) in lines.chunks(7).enumerate() {
+            let first_line = i * 7 + 1;
+
+            if block[0].trim() != format!("Monkey {}:", i) {
+                return Err(PuzzleError::new(format!("Expected 'Monkey {}:'", i)).at_line(first_line, &block[0]));
+            }
+
+            if let Some(separator) = block.get(6).filter(|line| !line.trim().is_empty()) {
+                return Err(PuzzleError::new("Expected an empty line between monkeys").at_line(first_line + 6, separator));
+            }
+
+            monkeys.push(Monkey::from_text(block, first_line)?);
+        }
+
+        if monkeys.len() < 2 {
+            return Err(PuzzleError::new("Expected at least two monkeys"));
+        }
+
+        // Make sure every monkey throws to monkeys that actually exist
+        for (i, monkey) in monkeys.iter().enumerate() {
+            if let Some(&target) = monkey.throw_targets.iter().find(|&&target| target >= monkeys.len()) {
+                return Err(PuzzleError::new(format!("Monkey {} throws to monkey {}, which does not exist", i, target)));
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(_solve(&mut monkeys.clone(), 20, false).into()))
+    }
+
+    fn part2(monkeys: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(_solve(&mut monkeys.clone(), 10_000, true).into()))
+    }
+}
+
+// Either relax worries using division (part 1) or using modulo (part 2)
+fn _solve(monkeys: &mut Vec<Monkey>, rounds: i32, use_modulo: bool) -> i64 {
+    let lcd = lcd(&monkeys);
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for _ in 0..monkeys[i].items_stack.len() {
+                let item = monkeys[i].items_stack[0];
+                
+                monkeys[i].items_stack.remove(0);
+
+                let mut worry_level = monkeys[i].inspect(item, use_modulo, lcd);
+
+                // Only in part 1
+                if !use_modulo {
+                    worry_level /= 3;
+                }
+
+                let to = monkeys[i].throw_to(worry_level);
+
+                monkeys[to].add_item(worry_level);
+
+                monkeys[i].inspections_performed += 1;
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|x| { x.inspections_performed });
+    monkeys.reverse();
+
+    return monkeys[0].inspections_performed * monkeys[1].inspections_performed;
+}
+
+fn lcd(monkeys: &Vec<Monkey>) -> i64 {
+    let mut lcd = 1;
+
+    for monkey in monkeys {
+        lcd *= monkey.divisible_by;
+    }
+
+    return lcd;
+}
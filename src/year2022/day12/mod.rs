@@ -0,0 +1,93 @@
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::point::Point;
+use crate::puzzle::{self, Answer, Input, Solution};
+use crate::search::{self, Path};
+
+// The level of each square, 'S' and 'E' included
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<char>,
+}
+
+impl Map {
+    fn level_at(&self, coord: Point) -> char {
+        match self.grid[coord] {
+            'S' => 'a',
+            'E' => 'z',
+            level => level,
+        }
+    }
+
+    // The squares next to a square that can be climbed to from it, at most one level up
+    fn climbable_from(&self, from: Point) -> impl Iterator<Item = Point> + '_ {
+        self.grid.neighbours(from.x, from.y).map(Point::from)
+            .filter(move |&to| self.level_at(to) as i32 - self.level_at(from) as i32 <= 1)
+    }
+
+    // The squares next to a square that it can be climbed to from, i.e. `climbable_from` backwards
+    fn climbable_to(&self, to: Point) -> impl Iterator<Item = Point> + '_ {
+        self.grid.neighbours(to.x, to.y).map(Point::from)
+            .filter(move |&from| self.level_at(to) as i32 - self.level_at(from) as i32 <= 1)
+    }
+
+    fn find(&self, level: char) -> Option<Point> {
+        self.grid.positions(|&c| c == level).next().map(Point::from)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    // The map along with the start and end position
+    type Parsed = (Map, Point, Point);
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let lines = puzzle::read_all_lines(lines)?;
+
+        let grid = Grid::parse(&lines, |c| match c {
+            'S' | 'E' | 'a'..='z' => Ok(c),
+            _ => Err(PuzzleError::new(format!("Invalid square '{}'", c))),
+        })?;
+
+        let map = Map { grid };
+
+        let start_position = map.find('S').ok_or_else(|| PuzzleError::new("There is no start 'S' on the map"))?;
+        let end_position = map.find('E').ok_or_else(|| PuzzleError::new("There is no end 'E' on the map"))?;
+
+        Ok((map, start_position, end_position))
+    }
+
+    fn part1((map, start_position, end_position): &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let path = search::bfs(*start_position, |&from| map.climbable_from(from), |&at| at == *end_position)
+            .ok_or_else(|| PuzzleError::new("There is no way up to the end"))?;
+
+        trace!("Shortest path:\n{}", render(map, &path));
+
+        Ok(Some(path.cost.into()))
+    }
+
+    // Going down from the end finds the closest square of the lowest level, 'S' being one of them
+    fn part2((map, _, end_position): &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let path = search::bfs(*end_position, |&to| map.climbable_to(to), |&at| map.level_at(at) == 'a')
+            .ok_or_else(|| PuzzleError::new("There is no way up to the end from any square of level 'a'"))?;
+
+        trace!("Shortest path (from the end):\n{}", render(map, &path));
+
+        Ok(Some(path.cost.into()))
+    }
+}
+
+// The map with the squares along the path in upper case
+fn render(map: &Map, path: &Path<Point>) -> String {
+    let mut grid = map.grid.clone();
+
+    for square in &path.states {
+        grid.set(square.x, square.y, grid[*square].to_ascii_uppercase());
+    }
+
+    grid.render(|&c| c)
+}
@@ -1,11 +1,11 @@
 use std::cmp::{max, Ordering};
-use std::fs::File;
-use std::io::{BufReader, Lines};
 use std::str::Chars;
-use crate::puzzle;
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Solution};
 
 #[derive(Debug, Clone)]
-struct Node {
+pub struct Node {
     children: Vec<Node>,
     value: i32,
     is_leaf: bool,
@@ -116,67 +116,78 @@ impl Node {
     }
 }
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
-    let all_lines = puzzle::read_all_lines(lines);
+pub struct Day13;
 
-    solve_1(&all_lines, test);
-    solve_2(&all_lines, test);
-}
+impl Solution for Day13 {
+    const TITLE: &'static str = "Distress Signal";
 
-fn solve_1(lines: &Vec<String>, test: bool) {
-    let mut list_pairs = Vec::<(Node, Node)>::new();
+    type Parsed = Vec<(Node, Node)>;
 
-    let mut lines = lines.iter();
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
 
-    loop {
-        let line_res = lines.next();
+        let mut list_pairs = Vec::<(Node, Node)>::new();
 
-        if line_res.is_none() {
-            break;
-        }
+        let mut lines = all_lines.iter().enumerate();
 
-        let line = line_res.unwrap();
+        loop {
+            let line_res = lines.next();
 
-        if line.is_empty() {
-            continue;
+            if line_res.is_none() {
+                break;
+            }
+
+            let (i, line) = line_res.unwrap();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let a = parse_line(line).at_line(i + 1, line)?;
+
+            let (i, line) = lines.next().ok_or_else(|| PuzzleError::new("Packet has no partner").at_line(i + 1, line))?;
+            let b = parse_line(line).at_line(i + 1, line)?;
+
+            list_pairs.push((a, b));
         }
 
-        let a = parse_line(line);
+        Ok(list_pairs)
+    }
 
-        let line_res = lines.next();
-        let line = line_res.unwrap();
-        let b = parse_line(line);
+    fn part1(list_pairs: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(solve_1(list_pairs).into()))
+    }
 
-        list_pairs.push((a, b));
+    fn part2(list_pairs: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(solve_2(list_pairs)?.into()))
     }
+}
 
+fn solve_1(list_pairs: &Vec<(Node, Node)>) -> usize {
     let mut in_right_order_sum = 0;
 
     for (i, list) in list_pairs.iter().enumerate() {
-        if is_in_right_order(&list.0, &list.1, test) {
+        if is_in_right_order(&list.0, &list.1) {
             in_right_order_sum += i + 1;
         }
     }
 
-    println!("Part 1: sum of indices being in right order: {}", in_right_order_sum);
+    return in_right_order_sum;
 }
 
-fn solve_2(lines: &Vec<String>, _: bool) {
+fn solve_2(list_pairs: &Vec<(Node, Node)>) -> PuzzleResult<usize> {
     let mut nodes = Vec::<Node>::new();
 
-    for line in lines {
-        if line.is_empty() {
-            continue;
-        }
-
-        nodes.push(parse_line(line));
+    for (a, b) in list_pairs {
+        nodes.push(a.clone());
+        nodes.push(b.clone());
     }
 
     const DIVIDER_PACKET_1: &str = "[[2]]";
     const DIVIDER_PACKER_2: &str = "[[6]]";
 
-    nodes.push(parse_line(&String::from(DIVIDER_PACKET_1)));
-    nodes.push(parse_line(&String::from(DIVIDER_PACKER_2)));
+    nodes.push(parse_line(&String::from(DIVIDER_PACKET_1))?);
+    nodes.push(parse_line(&String::from(DIVIDER_PACKER_2))?);
 
     nodes.sort_by(|a, b| {
         a.compare(b)
@@ -193,35 +204,34 @@ fn solve_2(lines: &Vec<String>, _: bool) {
             idx_second = i + 1;
         }
 
-        // if test {
-        //     println!("{}: {}", i, node.to_string());
-        // }
+        trace!("{}: {}", i, node.to_string());
     }
 
-    println!("Part 2: decoder key: {}", idx_first * idx_second);
+    return Ok(idx_first * idx_second);
 }
 
-fn is_in_right_order(left: &Node, right: &Node, _: bool) -> bool {
+fn is_in_right_order(left: &Node, right: &Node) -> bool {
     let res = left.compare(right);
 
-    // if test {
-    //     println!("==>");
-    //     println!("L: {}", left.to_string());
-    //     println!("R: {}", right.to_string());
-    //     println!("Result: {:?}", res);
-    //     println!();
-    // }
+    trace!("L: {}", left.to_string());
+    trace!("R: {}", right.to_string());
+    trace!("Result: {:?}", res);
 
     return res == Ordering::Less;
 }
 
-fn parse_line(line: &String) -> Node {
+fn parse_line(line: &String) -> PuzzleResult<Node> {
     let mut char_stream = line.chars();
 
-    return parse_list(&mut char_stream).children[0].clone();
+    let mut outer = parse_list(&mut char_stream, 0)?;
+
+    match (outer.children.len(), outer.children.first()) {
+        (1, Some(packet)) if !packet.is_leaf() => return Ok(outer.children.remove(0)),
+        _ => return Err(PuzzleError::new("Expected a single list")),
+    }
 }
 
-fn parse_list(char_stream: &mut Chars) -> Node {
+fn parse_list(char_stream: &mut Chars, depth: usize) -> PuzzleResult<Node> {
     let mut node = Node::new();
 
     let mut digits = String::new();
@@ -230,7 +240,11 @@ fn parse_list(char_stream: &mut Chars) -> Node {
         let next_res = char_stream.next();
 
         if next_res.is_none() {
-            return node;
+            if depth > 0 {
+                return Err(PuzzleError::new("Missing ']'"));
+            }
+
+            return Ok(node);
         }
 
         let c = next_res.unwrap();
@@ -242,7 +256,7 @@ fn parse_list(char_stream: &mut Chars) -> Node {
         }
 
         if !digits.is_empty() {
-            let as_num: i32 = digits.parse().expect("Failed to parse number");
+            let as_num: i32 = error::parse(&digits, "number")?;
 
             node.add_child(Node::new_leaf(as_num));
 
@@ -250,7 +264,7 @@ fn parse_list(char_stream: &mut Chars) -> Node {
         }
 
         if c == '[' {
-            let child = parse_list(char_stream);
+            let child = parse_list(char_stream, depth + 1)?;
 
             node.add_child(child);
 
@@ -258,9 +272,16 @@ fn parse_list(char_stream: &mut Chars) -> Node {
         }
 
         if c == ']' {
-            return node;
+            if depth == 0 {
+                return Err(PuzzleError::new("Unexpected ']'"));
+            }
+
+            return Ok(node);
         }
 
         // We simply ignore commas and whitespace
+        if c != ',' && !c.is_whitespace() {
+            return Err(PuzzleError::new(format!("Unexpected '{}'", c)));
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,223 @@
+use std::cmp::{max, min};
+
+use crate::year2022::day14::Element::{Air, Rock, Sand};
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::point::Point;
+use crate::puzzle::{self, Answer, Input, Part, Solution};
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Element {
+    Rock,
+    Sand,
+    Air,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<Element>,
+}
+
+impl Map {
+    fn new(width: usize, height: usize, x_offset: usize) -> Self {
+        return Map { grid: Grid::with_origin(x_offset as i64, 0, width, height, Air) };
+    }
+
+    fn draw_formation(&mut self, formation: Vec<Point>) {
+        for i in 0..formation.len() - 1 {
+            let cur = &formation[i];
+            let next = &formation[i + 1];
+
+            if cur.x != next.x {
+                self.draw_horizontal_formation(cur, next);
+
+                continue;
+            }
+
+            self.draw_vertical_formation(cur, next);
+        }
+    }
+
+    fn draw_horizontal_formation(&mut self, from: &Point, to: &Point) {
+        let width = to.x - from.x;
+
+        if width > 0 {
+            for i in 0..=width {
+                self.set(Point::new(from.x + i, from.y), Rock);
+            }
+
+            return;
+        }
+
+        for i in 0..=width.abs() {
+            self.set(Point::new(from.x - i, from.y), Rock);
+        }
+    }
+
+    fn draw_vertical_formation(&mut self, from: &Point, to: &Point) {
+        let height = to.y - from.y;
+
+        if height > 0 {
+            for i in 0..=height {
+                self.set(Point::new(from.x, from.y + i), Rock);
+            }
+
+            return;
+        }
+
+        for i in 0..=height.abs() {
+            self.set(Point::new(from.x, from.y - i), Rock);
+        }
+    }
+
+    fn render(&self) -> String {
+        self.grid.render(|element| match element {
+            Rock => '#',
+            Sand => '0',
+            Air => '.',
+        })
+    }
+
+    fn simulate_sand(&mut self, source: Point) -> bool {
+        let mut pos_grain = source;
+
+        'outer: loop {
+          // Can the grain move down, diagonally to the left or diagonally to the right?
+            let moves_to_check = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)].map(|offset| pos_grain + offset);
+
+            for c in moves_to_check {
+                if !self.coord_on_map(c) {
+                    return false;
+                }
+
+                if self.at(c) == Air {
+                    pos_grain = c;
+
+                    continue 'outer;
+                }
+            }
+
+            // Sand cannot move, it comes to rest
+            self.set(pos_grain, Sand);
+
+            return true;
+        }
+    }
+
+    fn at(&self, c: Point) -> Element {
+        return self.grid[c];
+    }
+
+    fn set(&mut self, at: Point, element: Element) {
+        self.grid[at] = element;
+    }
+
+    fn coord_on_map(&self, c: Point) -> bool {
+        return self.grid.contains(c.x, c.y);
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const TITLE: &'static str = "Regolith Reservoir";
+    const PARTS: &'static [Part] = &[Part::One];
+
+    // The cave with all rock formations drawn into it
+    type Parsed = Map;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
+
+        let (formations, boundaries) = parse_rock_formations(&all_lines)?;
+
+        // For simplicity we assume that min_y is always 0 (therefore, y_offset is also 0)
+        let x_offset = boundaries.min_x as usize;
+        let width = (boundaries.max_x - boundaries.min_x + 1) as usize;
+        let height = boundaries.max_y as usize + 1;
+
+        let mut map = Map::new(width, height, x_offset);
+
+        for formation in formations {
+            map.draw_formation(formation);
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut map = map.clone();
+
+        trace!("Cave before the sand came:\n{}", map.render().trim_end());
+
+        let mut grains_resting = 0;
+
+        while map.simulate_sand(Point::new(500, 0)) {
+            grains_resting += 1;
+        }
+
+        trace!("Cave after the sand came to rest:\n{}", map.render().trim_end());
+
+        return Ok(Some(grains_resting.into()));
+    }
+}
+
+struct Boundaries {
+    min_x: i64,
+    max_x: i64,
+    min_y: i64,
+    max_y: i64,
+}
+
+fn parse_rock_formations(lines: &Vec<String>) -> PuzzleResult<(Vec<Vec<Point>>, Boundaries)> {
+    let mut formations = Vec::<Vec<Point>>::new();
+
+    // 500 as initial value because that is the origin of sand and has to be part of the map
+    let mut min_x = 500;
+    let mut max_x = 500;
+    let mut min_y = 0;
+    let mut max_y = 0;
+
+    for (i, line) in lines.iter().enumerate() {
+        let instructions = line.split(" -> ");
+
+        let mut formation = Vec::<Point>::new();
+
+        for instruction in instructions {
+            let coord = parse_coord(instruction).at_line(i + 1, line)?;
+
+            if let Some(last) = formation.last() {
+                if last.x != coord.x && last.y != coord.y {
+                    return Err(PuzzleError::new("Rock formations must consist of straight lines").at_line(i + 1, line));
+                }
+            }
+
+            let Point { x, y } = coord;
+
+            min_x = min(min_x, x);
+            max_x = max(max_x, x);
+            min_y = min(min_y, y);
+            max_y = max(max_y, y);
+
+            formation.push(Point { x, y });
+        }
+
+        formations.push(formation);
+    }
+
+    return Ok((formations, Boundaries { min_x, max_x, min_y, max_y }));
+}
+
+fn parse_coord(s: &str) -> PuzzleResult<Point> {
+    let (x, y) = s.split_once(",").ok_or_else(|| PuzzleError::new(format!("Invalid coordinate '{}'", s)))?;
+
+    let x: i64 = error::parse(x, "x coordinate")?;
+    let y: i64 = error::parse(y, "y coordinate")?;
+
+    if x < 0 || y < 0 {
+        return Err(PuzzleError::new(format!("Coordinate '{}' lies outside of the cave", s)));
+    }
+
+    return Ok(Point { x, y });
+}
\ No newline at end of file
@@ -0,0 +1,231 @@
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::error::{self, PuzzleError, PuzzleResult};
+use crate::grid::Grid;
+use crate::interval::{Interval, IntervalSet};
+use crate::log::{self, Level};
+use crate::params::Params;
+use crate::point::Point;
+use crate::puzzle::{self, Answer, Input, Solution};
+
+// The sensors and beacons are only drawn up to this many positions, see `Day15::part1`
+const MAX_DRAWN_POSITIONS: usize = 10_000;
+
+pub struct Day15;
+
+pub struct Signals {
+    sensors_beacons: Vec<(Point, Point)>,
+    boundaries: Boundaries,
+}
+
+impl Solution for Day15 {
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    type Parsed = Signals;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
+
+        let (sensors_beacons, boundaries) = parse_signals(&all_lines)?;
+
+        Ok(Signals { sensors_beacons, boundaries })
+    }
+
+    fn part1(signals: &Self::Parsed, params: &Params) -> PuzzleResult<Option<Answer>> {
+        let Signals { sensors_beacons, boundaries } = signals;
+
+        let row = params.get::<i64>("row")?;
+
+        // Drawing the map takes a cell per position, which is only feasible for the example
+        if log::enabled(Level::Trace) {
+            let width = (boundaries.max_x - boundaries.min_x + 1) as usize;
+            let height = (boundaries.max_y - boundaries.min_y + 1) as usize;
+
+            if width * height <= MAX_DRAWN_POSITIONS {
+                let mut map = Map::new(width, height, -boundaries.min_x as usize, -boundaries.min_y as usize);
+
+                for pair in sensors_beacons {
+                    map.add_sensor_and_beacon(pair.0, pair.1);
+                }
+
+                trace!("Sensors and beacons, {} wide and {} high:\n{}", width, height, map.to_string().trim_end());
+            } else {
+                trace!("Not drawing the sensors and beacons, the map would be {} wide and {} high", width, height);
+            }
+        }
+
+        let non_beacon_positions = count_non_beacon_positions(row, sensors_beacons);
+
+        return Ok(Some(non_beacon_positions.into()));
+    }
+
+    fn part2(signals: &Self::Parsed, params: &Params) -> PuzzleResult<Option<Answer>> {
+        // The distress beacon is somewhere in a square from 0,0 to search_max,search_max
+        let search_max = params.get::<i64>("search_max")?;
+
+        let area = Interval::new(0, search_max).ok_or_else(|| PuzzleError::new("The search_max must not be negative"))?;
+
+        let possible_position = find_distress_beacon(area, &signals.sensors_beacons)?;
+
+        let tuning_frequency = possible_position.x * 4_000_000 + possible_position.y;
+
+        return Ok(Some(tuning_frequency.into()));
+    }
+}
+
+// The beacon is the one position in the area no sensor covers
+fn find_distress_beacon(area: Interval, sensor_beacons: &Vec<(Point, Point)>) -> PuzzleResult<Point> {
+    for y in area.start..=area.end {
+        if let Some(gap) = covered_in_row(y, sensor_beacons).complement(area).intervals().first() {
+            return Ok(Point::new(gap.start, y));
+        }
+    }
+
+    Err(PuzzleError::new("There is no position left for the distress beacon"))
+}
+
+fn count_non_beacon_positions(y: i64, sensor_beacons: &Vec<(Point, Point)>) -> i64 {
+    // Beacons are covered by their sensor, but they are not positions without a beacon
+    let beacons_in_row: HashSet<Point> = sensor_beacons.iter().map(|sb| sb.1).filter(|beacon| beacon.y == y).collect();
+
+    return covered_in_row(y, sensor_beacons).len() - beacons_in_row.len() as i64;
+}
+
+// The positions of a row that are at most as far away from a sensor as the sensor's beacon, i.e.
+// where there can be no other beacon
+fn covered_in_row(y: i64, sensor_beacons: &Vec<(Point, Point)>) -> IntervalSet {
+    sensor_beacons.iter().filter_map(|(sensor, beacon)| {
+        let reach = sensor.manhattan_distance(*beacon) - (sensor.y - y).abs();
+
+        Interval::new(sensor.x - reach, sensor.x + reach)
+    }).collect()
+}
+
+struct Boundaries {
+    min_x: i64,
+    max_x: i64,
+    min_y: i64,
+    max_y: i64,
+}
+
+fn parse_signals(lines: &Vec<String>) -> PuzzleResult<(Vec<(Point, Point)>, Boundaries)> {
+    let mut sensors_beacons = Vec::<(Point, Point)>::new();
+
+    // These initial values could lead to issues in case the input is, e.g., very far to the "right".
+    // That would cause a too big boundary box.
+    let mut min_x = 0;
+    let mut max_x = 0;
+    let mut min_y = 0;
+    let mut max_y = 0;
+
+    let re = Regex::new(r"Sensor at x=([-\d]*), y=([-\d]*): closest beacon is at x=([-\d]*), y=([-\d]*)").expect("Failed to create RegEx");
+
+
+    for (i, line) in lines.iter().enumerate() {
+        let at_line = |err: PuzzleError| err.at_line(i + 1, line);
+
+        let cap = match re.captures(line) {
+            Some(cap) => cap,
+            None => return Err(at_line(PuzzleError::new("Did not find sensor and beacon position in line"))),
+        };
+
+        let sensor_x = error::parse(&cap[1], "sensor's x value").map_err(at_line)?;
+        let sensor_y = error::parse(&cap[2], "sensor's y value").map_err(at_line)?;
+        let beacon_x = error::parse(&cap[3], "beacon's x value").map_err(at_line)?;
+        let beacon_y = error::parse(&cap[4], "beacon's y value").map_err(at_line)?;
+
+        let sensor = Point { x: sensor_x, y: sensor_y };
+        let beacon = Point { x: beacon_x, y: beacon_y };
+
+        sensors_beacons.push((sensor, beacon));
+
+        let distance = sensor.manhattan_distance(beacon);
+
+        // Set the initial values
+        if i == 0 {
+            min_x = sensor_x - distance;
+            min_y = sensor_y - distance;
+            max_x = sensor_x + distance;
+            max_y = sensor_y + distance;
+
+            continue;
+        }
+
+        min_x = min(min_x, sensor_x - distance);
+        min_y = min(min_y, sensor_y - distance);
+        max_x = max(max_x, sensor_x + distance);
+        max_y = max(max_y, sensor_y + distance);
+    }
+
+    return Ok((sensors_beacons, Boundaries { min_x, max_x, min_y, max_y }));
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Thing {
+    Beacon,
+    Sensor,
+    Nothing,
+    Unknown,
+}
+
+struct Map {
+    grid: Grid<Thing>,
+}
+
+impl Map {
+    fn new(width: usize, height: usize, x_offset: usize, y_offset: usize) -> Self {
+        return Map { grid: Grid::with_origin(-(x_offset as i64), -(y_offset as i64), width, height, Thing::Unknown) };
+    }
+
+    fn add_sensor_and_beacon(&mut self, sensor: Point, beacon: Point) {
+        let distance = sensor.manhattan_distance(beacon);
+
+        for i in -distance..=distance {
+            for j in -distance..=distance {
+                let c = sensor + Point::new(i, j);
+
+                if c.manhattan_distance(sensor) <= distance {
+                    if self.at(c) == Thing::Unknown {
+                        self.set(c, Thing::Nothing);
+                    }
+                }
+            }
+        }
+
+        self.set(sensor, Thing::Sensor);
+        self.set(beacon, Thing::Beacon);
+    }
+
+    fn to_string(&self) -> String {
+        let mut str_buf = String::new();
+        let (_, y_origin) = self.grid.origin();
+
+        let rendered = self.grid.render(|thing| match thing {
+            Thing::Beacon => { 'B' }
+            Thing::Sensor => { 'S' }
+            Thing::Nothing => { '#' }
+            Thing::Unknown => { '.' }
+        });
+
+        // Each row is labeled with its y coordinate
+        for (i, row) in rendered.lines().enumerate() {
+            str_buf.push_str(&(y_origin + i as i64).to_string());
+            str_buf.push('\t');
+            str_buf.push_str(row);
+            str_buf.push('\n');
+        }
+
+        return str_buf;
+    }
+
+    fn at(&self, c: Point) -> Thing {
+        return self.grid[c];
+    }
+
+    fn set(&mut self, at: Point, thing: Thing) {
+        self.grid[at] = thing;
+    }
+}
@@ -1,53 +1,63 @@
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufReader, Lines};
 
-use regex::Regex;
+use regex::{Captures, Regex};
 
-use crate::puzzle;
+use crate::cancel::{self, CancellationToken};
+use crate::error::{self, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Part, Solution};
 
 const START_VALVE: &str = "AA";
 
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
-    let all_lines = puzzle::read_all_lines(lines);
+pub struct Day16;
 
-    let valves_map = parse_valves(&all_lines);
+impl Solution for Day16 {
+    const TITLE: &'static str = "Proboscidea Volcanium";
+    const PARTS: &'static [Part] = &[Part::One];
 
-    let start_valve = valves_map.get(START_VALVE).expect("Did not find start valve in map");
+    type Parsed = HashMap<String, Valve>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
+
+        return parse_valves(&all_lines);
+    }
+
+    fn part1(valves_map: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        return release_most_pressure(valves_map).map(|pressure| Some(pressure.into()));
+    }
+}
+
+fn release_most_pressure(valves_map: &HashMap<String, Valve>) -> PuzzleResult<i32> {
+    let start_valve = valves_map.get(START_VALVE).ok_or_else(|| PuzzleError::new(format!("There is no start valve {}", START_VALVE)))?;
 
     let mut valves_by_flow_rate: Vec<&Valve> = valves_map.values().collect();
     valves_by_flow_rate.sort_by(|a, b| a.flow_rate.cmp(&b.flow_rate));
     valves_by_flow_rate.reverse();
 
-    println!("{:?}", valves_by_flow_rate);
-
-    // println!("Loop {}", has_loop(&"A".to_string(), &vec!["A".to_string(), "B".to_string(), "C".to_string()]));
-    // println!("Loop {}", has_loop(&"A".to_string(), &vec!["A".to_string(), "B".to_string(), "A".to_string()]));
-    // println!("Loop {}", has_loop(&"B".to_string(), &vec!["A".to_string(), "B".to_string(), "A".to_string()]));
-
+    debug!("Valves by flow rate: {:?}", valves_by_flow_rate);
 
     let mut remaining_valves = HashSet::<String>::new();
-    for (valve_name, _) in &valves_map {
+    for (valve_name, _) in valves_map {
         remaining_valves.insert(valve_name.clone());
     }
 
-    println!("{}", approximate_possible_max_pressure_release(30, &valves_by_flow_rate, &remaining_valves.clone()));
+    debug!("Approximate maximum pressure release: {}", approximate_possible_max_pressure_release(30, &valves_by_flow_rate, &remaining_valves.clone()));
 
     let max_release_forecast = trace_path(
         &start_valve,
         30,
         0,
         0,
-        &valves_map,
+        valves_map,
         remaining_valves,
         &valves_by_flow_rate,
-        Vec::<String>::with_capacity(30));
-
-    println!("Part 1: Forecast maximum pressure release: {}", max_release_forecast);
+        Vec::<String>::with_capacity(30),
+        &cancel::current())?;
 
-    return;
+    return Ok(max_release_forecast);
 }
 
 fn approximate_possible_max_pressure_release(mut time_remaining: i32, valves_by_flow_rate: &Vec<&Valve>, remaining_valves: &HashSet<String>) -> i32 {
@@ -89,13 +99,15 @@ fn has_loop(name: &String, path: &Vec<String>) -> bool {
     return false;
 }
 
-fn trace_path(cur_valve: &Valve, time_remaining: i32, release_forecast: i32, max_release_forecast: i32, map: &HashMap<String, Valve>, mut remaining_valves: HashSet<String>, valves_by_flow_rate: &Vec<&Valve>, mut path: Vec<String>) -> i32 {
+fn trace_path(cur_valve: &Valve, time_remaining: i32, release_forecast: i32, max_release_forecast: i32, map: &HashMap<String, Valve>, mut remaining_valves: HashSet<String>, valves_by_flow_rate: &Vec<&Valve>, mut path: Vec<String>, token: &CancellationToken) -> PuzzleResult<i32> {
+    token.check()?;
+
     if time_remaining <= 1 { // || release_forecast + approximate_possible_max_pressure_release(time_remaining, valves_by_flow_rate, &remaining_valves) <= max_release_forecast
-        return release_forecast;
+        return Ok(release_forecast);
     }
 
     if has_loop(&cur_valve.name, &path) {
-        return release_forecast;
+        return Ok(release_forecast);
     }
 
     path.push(cur_valve.name.clone());
@@ -119,7 +131,7 @@ fn trace_path(cur_valve: &Valve, time_remaining: i32, release_forecast: i32, max
         for neighbor in &cur_valve.neighbors {
             let neighbor_valve = map.get(neighbor).expect("Neighbor valve not found in map");
 
-            max_forecast = max(max_forecast, trace_path(neighbor_valve, time_remaining - 2, forecast_open, max_release_forecast, map, remaining_valves.clone(), valves_by_flow_rate, path.clone()))
+            max_forecast = max(max_forecast, trace_path(neighbor_valve, time_remaining - 2, forecast_open, max_release_forecast, map, remaining_valves.clone(), valves_by_flow_rate, path.clone(), token)?)
         }
     }
 
@@ -127,16 +139,16 @@ fn trace_path(cur_valve: &Valve, time_remaining: i32, release_forecast: i32, max
     for neighbor in &cur_valve.neighbors {
         let neighbor_valve = map.get(neighbor).expect("Neighbor valve not found in map");
 
-        max_forecast = max(max_forecast, trace_path(neighbor_valve, time_remaining - 1, release_forecast, max_release_forecast, map, remaining_valves.clone(), valves_by_flow_rate, path.clone()))
+        max_forecast = max(max_forecast, trace_path(neighbor_valve, time_remaining - 1, release_forecast, max_release_forecast, map, remaining_valves.clone(), valves_by_flow_rate, path.clone(), token)?)
     }
 
     // traces.insert((cur_valve.name.clone(), time_remaining), max_forecast);
 
-    return max_forecast;
+    return Ok(max_forecast);
 }
 
 #[derive(Debug, Clone)]
-struct Valve {
+pub struct Valve {
     neighbors: Vec<String>,
     name: String,
     flow_rate: i32,
@@ -156,17 +168,17 @@ impl Valve {
     }
 }
 
-fn parse_valves(lines: &Vec<String>) -> HashMap<String, Valve> {
+fn parse_valves(lines: &Vec<String>) -> PuzzleResult<HashMap<String, Valve>> {
     let mut valves_map = HashMap::<String, Valve>::new();
 
     let re = Regex::new(r"Valve (.*) has flow rate=(\d*); tunnels? leads? to valves? (.*)").expect("Failed to create RegEx");
 
     // First, create all valves
-    for line in lines {
-        let cap = re.captures(line).unwrap();
+    for (i, line) in lines.iter().enumerate() {
+        let cap = captures(&re, i, line)?;
 
         let valve_name = cap.get(1).expect("Failed to match valve name").as_str();
-        let flow_rate = cap.get(2).expect("Failed to match flow rate").as_str().parse().expect("Failed to parse flow rate");
+        let flow_rate = error::parse(cap.get(2).expect("Failed to match flow rate").as_str(), "flow rate").map_err(|err| err.at_line(i + 1, line))?;
 
         let valve = Valve::new(valve_name.to_string(), flow_rate);
 
@@ -174,14 +186,18 @@ fn parse_valves(lines: &Vec<String>) -> HashMap<String, Valve> {
     }
 
     // Second, link them
-    for line in lines {
-        let cap = re.captures(line).unwrap();
+    for (i, line) in lines.iter().enumerate() {
+        let cap = captures(&re, i, line)?;
 
         let valve_name = cap.get(1).expect("Failed to match valve name").as_str();
         let neighbors = cap.get(3).expect("Failed to match neighbors").as_str().split(", ");
 
         let mut neighbors: Vec<&str> = neighbors.collect();
 
+        if let Some(unknown) = neighbors.iter().find(|n| !valves_map.contains_key(**n)) {
+            return Err(PuzzleError::new(format!("Tunnel leads to unknown valve '{}'", unknown)).at_line(i + 1, line));
+        }
+
         neighbors.sort_by(|a, b| {
             valves_map.get(*a).unwrap().flow_rate.cmp(&valves_map.get(*b).unwrap().flow_rate)
         });
@@ -195,5 +211,9 @@ fn parse_valves(lines: &Vec<String>) -> HashMap<String, Valve> {
         }
     }
 
-    return valves_map;
+    return Ok(valves_map);
+}
+
+fn captures<'l>(re: &Regex, i: usize, line: &'l str) -> PuzzleResult<Captures<'l>> {
+    re.captures(line).ok_or_else(|| PuzzleError::new("Expected a valve like 'Valve AA has flow rate=0; tunnels lead to valves DD, II'").at_line(i + 1, line))
 }
\ No newline at end of file
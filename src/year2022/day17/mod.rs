@@ -0,0 +1,262 @@
+use crate::year2022::day17::RockType::{HBar, L, Plus, Square, VBar};
+use crate::cycle::Cycle;
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::point::{Direction, Point};
+use crate::puzzle::{self, Answer, Input, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const TITLE: &'static str = "Pyroclastic Flow";
+
+    type Parsed = String;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
+        let jet_pattern = all_lines.first().ok_or_else(|| PuzzleError::new("There is no jet pattern"))?;
+
+        if jet_pattern.is_empty() {
+            return Err(PuzzleError::new("The jet pattern is empty").at_line(1, jet_pattern));
+        }
+
+        if let Some(c) = jet_pattern.chars().find(|c| *c != '<' && *c != '>') {
+            return Err(PuzzleError::new(format!("Invalid jet '{}'", c)).at_line(1, jet_pattern));
+        }
+
+        return Ok(jet_pattern.clone());
+    }
+
+    fn part1(jet_pattern: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut map = Map::new(7);
+
+        let mut jet = Jet::new(jet_pattern.clone());
+
+        simulate_n_rocks(0, 2022, &mut jet, &mut map);
+
+        trace!("The tower after 2022 rocks:\n{}", map.render());
+
+        return Ok(Some(map.height().into()));
+    }
+
+    fn part2(jet_pattern: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        return Ok(Some(simulate_total_rocks(jet_pattern).into()));
+    }
+}
+
+// The rows at the top of the tower taken into account for telling whether the simulation repeats
+const SURFACE_ROWS: usize = 32;
+
+fn simulate_total_rocks(jet_pattern: &String) -> i64 {
+    const TOTAL_ROCKS: usize = 1_000_000_000_000;
+
+    let mut map = Map::new(7);
+
+    let mut jet = Jet::new(jet_pattern.clone());
+
+    // Once the same kind of rock falls at the same point of the jet pattern onto the same surface,
+    // the tower grows the same way as it did before
+    let cycle = Cycle::detect(|i| {
+        if i > 0 {
+            simulate_rock(i - 1, &mut jet, &mut map);
+        }
+
+        ((jet.idx, i % 5, map.surface()), map.height() as i64)
+    });
+
+    debug!("Part 2: the tower repeats itself every {} rocks after {} rocks", cycle.period, cycle.start);
+
+    return cycle.extrapolate(TOTAL_ROCKS);
+}
+
+fn simulate_n_rocks(offset: usize, n: usize, jet: &mut Jet, map: &mut Map) {
+    for i in 0..n {
+        simulate_rock(i + offset, jet, map);
+    }
+}
+
+fn simulate_rock(i: usize, jet: &mut Jet, map: &mut Map) {
+    let rock_type: RockType = match i % 5 {
+        0 => { HBar }
+        1 => { Plus }
+        2 => { L }
+        3 => { VBar }
+        4 => { Square }
+        _ => { panic!("This should never happen") }
+    };
+
+    let mut rock = Rock::new(Point::new(2, 0), rock_type);
+
+    map.add_new_rows(3 + rock.height());
+
+    loop {
+        let direction = jet.next().unwrap();
+
+        // Whether the rock is pushed into a wall is up to `fits_in`
+        let moved = rock.coord.step(if direction == '<' { Direction::Left } else { Direction::Right });
+        if map.fits_in(&moved, &rock) {
+            rock.move_to(moved);
+        }
+
+        let fallen = rock.coord.step(Direction::Down);
+        if map.fits_in(&fallen, &rock) {
+            rock.move_to(fallen);
+        } else {
+            map.add_rock(&rock.coord, &rock);
+
+            break;
+        }
+    }
+
+    map.cleanup();
+}
+
+struct Jet {
+    pattern: String,
+    idx: usize,
+}
+
+impl Jet {
+    fn new(pattern: String) -> Jet {
+        return Jet {
+            pattern,
+            idx: 0,
+        };
+    }
+}
+
+impl Iterator for Jet {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.pattern.chars().nth(self.idx);
+
+        self.idx += 1;
+
+        if self.idx == self.pattern.len() {
+            self.idx = 0;
+        }
+
+        return c;
+    }
+}
+
+struct Rock {
+    coord: Point,
+    kind: RockType,
+}
+
+impl Rock {
+    fn new(coord: Point, kind: RockType) -> Self {
+        return Rock { coord, kind };
+    }
+
+    fn move_to(&mut self, to: Point) {
+        self.coord = to;
+    }
+
+    fn occupied_rel_coords(&self) -> Vec<Point> {
+        match self.kind {
+            RockType::HBar => {
+                vec![Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(3, 0)]
+            }
+            RockType::Plus => {
+                vec![Point::new(1, 0), Point::new(0, 1), Point::new(1, 1), Point::new(2, 1), Point::new(1, 2)]
+            }
+            RockType::L => {
+                vec![Point::new(2, 0), Point::new(2, 1), Point::new(0, 2), Point::new(1, 2), Point::new(2, 2)]
+            }
+            RockType::VBar => {
+                vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3)]
+            }
+            RockType::Square => {
+                vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]
+            }
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self.kind {
+            HBar => { 1 }
+            Plus => { 3 }
+            L => { 3 }
+            VBar => { 4 }
+            Square => { 2 }
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum RockType {
+    HBar,
+    Plus,
+    L,
+    VBar,
+    Square,
+}
+
+struct Map {
+    grid: Grid<bool>,
+}
+
+impl Map {
+    fn new(width: usize) -> Self {
+        return Map { grid: Grid::new(width, 0, false) };
+    }
+
+    fn add_new_rows(&mut self, n: usize) {
+        self.grid.insert_rows(0, n, false);
+    }
+
+    fn add_rock(&mut self, at: &Point, rock: &Rock) {
+        for coord in rock.occupied_rel_coords() {
+            self.set(coord + *at);
+        }
+    }
+
+    fn fits_in(&self, at: &Point, rock: &Rock) -> bool {
+        for coord in rock.occupied_rel_coords() {
+            let c = coord + *at;
+
+            // Off the chamber to the right or below its floor
+            if !self.grid.contains(c.x, c.y) {
+                return false;
+            }
+
+            if self.at(c) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    fn height(&self) -> usize {
+        return self.grid.height();
+    }
+
+    fn surface(&self) -> Vec<bool> {
+        return self.grid.rows().take(SURFACE_ROWS).flatten().copied().collect();
+    }
+
+    // The tower from the top, rock as '@'
+    fn render(&self) -> String {
+        return self.grid.render(|&rock| if rock { '@' } else { '.' }).trim_end().to_string();
+    }
+
+    fn at(&self, c: Point) -> bool {
+        return self.grid[c];
+    }
+
+    fn set(&mut self, c: Point) {
+        self.grid[c] = true;
+    }
+
+    // Removes the empty rows at the top
+    fn cleanup(&mut self) {
+        let empty = self.grid.rows().take_while(|row| !row.contains(&true)).count();
+
+        self.grid.remove_rows(0, empty);
+    }
+}
@@ -0,0 +1,118 @@
+use std::cmp::max;
+use std::collections::HashSet;
+
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::point::Point3;
+use crate::puzzle::{Answer, Input, Solution};
+use crate::search;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const TITLE: &'static str = "Boiling Boulders";
+
+    type Parsed = Vec<Point3>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut cubes: Vec<Point3> = lines.enumerate().map(|(i, line)| {
+            let line = line?;
+
+            parse_cube(&line).at_line(i + 1, &line)
+        }).collect::<PuzzleResult<Vec<Point3>>>()?;
+
+        // Move all cubes by 1,1,1 in order to get them away from the border; this required for the
+        // algorithm in part 2 and does not affect computations for part 1
+        for i in 0..cubes.len() {
+            cubes[i] = cubes[i] + Point3::new(1, 1, 1);
+        }
+
+        return Ok(cubes);
+    }
+
+    fn part1(cubes: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        return Ok(Some(count_surface(cubes).into()));
+    }
+
+    fn part2(cubes: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        return Ok(Some(count_outer_surface(cubes).into()));
+    }
+}
+
+fn count_surface(cubes: &Vec<Point3>) -> usize {
+    let cubes_set: HashSet<&Point3> = cubes.iter().collect();
+
+    let covered_sides = cubes.iter().fold(0, |acc, cube| {
+        acc + cube.neighbours().iter().filter(|other| cubes_set.contains(other)).count()
+    });
+
+    // Vs.: Not sure which is nicer though...
+    // let mut covered_sides = 0;
+    //
+    // for cube in &cubes {
+    //     for other in &cube.potentially_adjacent_cubes() {
+    //         if cubes_set.contains(other) {
+    //             covered_sides += 1;
+    //         }
+    //     }
+    // }
+
+    let total_sides = cubes.len() * 6;
+
+    return total_sides - covered_sides;
+}
+
+fn count_outer_surface(cubes: &Vec<Point3>) -> usize {
+    let cubes_set: HashSet<&Point3> = cubes.iter().collect();
+
+    let (max_x, max_y, max_z) = bounds(&cubes);
+
+    let is_in_box = |cube: &Point3| {
+        cube.x >= 0 && cube.y >= 0 && cube.z >= 0 && cube.x <= max_x + 1 && cube.y <= max_y + 1 && cube.z <= max_z + 1
+    };
+
+    // As we shifted all the droplet's cubes, these coordinates are guaranteed to not be part
+    // of the droplet. The air around the droplet is what can be reached from there without
+    // passing through the droplet, within a box just big enough to go all around it.
+    let outside_air = search::reachable(Point3::new(0, 0, 0), |cube| {
+        cube.neighbours().into_iter().filter(|adjacent| is_in_box(adjacent) && !cubes_set.contains(adjacent))
+    });
+
+    return outside_air.keys()
+        .map(|cube| cube.neighbours().iter().filter(|adjacent| cubes_set.contains(adjacent)).count())
+        .sum();
+}
+
+fn parse_cube(s: &String) -> PuzzleResult<Point3> {
+    let splits: Vec<&str> = s.split(",").collect();
+
+    if splits.len() != 3 {
+        return Err(PuzzleError::new("Expected cube coords like '2,2,2'"))
+    }
+
+    let x = error::parse(splits[0], "x")?;
+    let y = error::parse(splits[1], "y")?;
+    let z = error::parse(splits[2], "z")?;
+
+    // Part 2 relies on nothing being below (or left of, or in front of) the droplet's cubes
+    if x < 0 || y < 0 || z < 0 {
+        return Err(PuzzleError::new("Point3 coords must not be negative"))
+    }
+
+    Ok(Point3::new(x, y, z))
+}
+
+fn bounds(cubes: &Vec<Point3>) -> (i64, i64, i64) {
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut max_z = 0;
+
+    for cube in cubes {
+        max_x = max(max_x, cube.x);
+        max_y = max(max_y, cube.y);
+        max_z = max(max_z, cube.z);
+    }
+
+    (max_x, max_y, max_z)
+}
+
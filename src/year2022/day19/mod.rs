@@ -1,55 +1,67 @@
 use std::cmp::{max, min};
-use std::fs::File;
-use std::io::{BufReader, Lines};
 
 use regex::Regex;
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
-    let blueprints: Vec<Blueprint> = lines.map(|line| {
-        Blueprint::from_text(&line.expect("Failed to read line"))
-    }).collect();
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Solution};
 
-    println!("===> Part 1");
+pub struct Day19;
 
-    let mut sum_quality_levels = 0;
+impl Solution for Day19 {
+    const TITLE: &'static str = "Not Enough Minerals";
 
-    for (idx, blueprint) in blueprints.iter().enumerate() {
-        let geode_production = blueprint.simulate(24, Robots {
-            ore_robots: 1,
-            clay_robots: 0,
-            obsidian_robots: 0,
-            geode_robots: 0,
-        }, 0, 0, 0, 0, 0);
+    type Parsed = Vec<Blueprint>;
 
-        println!("Blueprint {}: Geode production: {}", idx + 1, geode_production);
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        lines.enumerate().map(|(i, line)| {
+            let line = line?;
 
-        sum_quality_levels += (idx as i32 + 1) * geode_production;
+            Blueprint::from_text(&line).at_line(i + 1, &line)
+        }).collect::<PuzzleResult<Vec<Blueprint>>>()
     }
 
-    println!("Sum of blueprints' quality levels: {}", sum_quality_levels);
+    fn part1(blueprints: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut sum_quality_levels = 0;
 
-    println!("===> Part 2");
+        for (idx, blueprint) in blueprints.iter().enumerate() {
+            let geode_production = blueprint.simulate(24, Robots {
+                ore_robots: 1,
+                clay_robots: 0,
+                obsidian_robots: 0,
+                geode_robots: 0,
+            }, 0, 0, 0, 0, 0);
 
-    let mut prod_quality_levels = 1;
+            debug!("Part 1: Blueprint {}: Geode production: {}", idx + 1, geode_production);
 
-    for i in 0..min(3, blueprints.len()) {
-        let geode_production = blueprints[i].simulate(32, Robots {
-            ore_robots: 1,
-            clay_robots: 0,
-            obsidian_robots: 0,
-            geode_robots: 0,
-        }, 0, 0, 0, 0, 0);
-
-        println!("Blueprint {}: Geode production: {}", i + 1, geode_production);
+            sum_quality_levels += (idx as i32 + 1) * geode_production;
+        }
 
-        prod_quality_levels *= geode_production;
+        Ok(Some(sum_quality_levels.into()))
     }
 
-    println!("Product of blueprints' productions: {}", prod_quality_levels);
+    fn part2(blueprints: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut prod_quality_levels = 1;
+
+        for i in 0..min(3, blueprints.len()) {
+            let geode_production = blueprints[i].simulate(32, Robots {
+                ore_robots: 1,
+                clay_robots: 0,
+                obsidian_robots: 0,
+                geode_robots: 0,
+            }, 0, 0, 0, 0, 0);
+
+            debug!("Part 2: Blueprint {}: Geode production: {}", i + 1, geode_production);
+
+            prod_quality_levels *= geode_production;
+        }
+
+        Ok(Some(prod_quality_levels.into()))
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Blueprint {
+pub struct Blueprint {
     costs_ore_robot: Costs,
     costs_clay_robot: Costs,
     costs_obsidian_robot: Costs,
@@ -57,39 +69,39 @@ struct Blueprint {
 }
 
 impl Blueprint {
-    fn from_text(s: &String) -> Blueprint {
+    fn from_text(s: &String) -> PuzzleResult<Blueprint> {
         let re = Regex::new(r"Blueprint [\d]+: Each ore robot costs ([\d]+) ore\. Each clay robot costs ([\d]+)* ore\. Each obsidian robot costs ([\d]+) ore and ([\d]+) clay\. Each geode robot costs ([\d]+) ore and ([\d]+) obsidian\.").expect("Failed to create RegEx");
 
-        let captures = re.captures(s);
-
-        if captures.is_none() {
-            panic!("Failed to match line with RegEx");
-        }
+        let captures = match re.captures(s) {
+            Some(captures) => captures,
+            None => return Err(PuzzleError::new("Line is not a blueprint")),
+        };
 
-        let captures = captures.unwrap();
+        // The regex only matches digits, the numbers can still be out of range though
+        let cost = |i: usize, what: &str| error::parse(captures.get(i).map_or("", |m| m.as_str()), what);
 
-        return Blueprint {
+        return Ok(Blueprint {
             costs_ore_robot: Costs {
-                ore: captures[1].parse().expect("Failed to parse ore costs for ore robot"),
+                ore: cost(1, "ore costs for ore robot")?,
                 clay: 0,
                 obsidian: 0,
             },
             costs_clay_robot: Costs {
-                ore: captures[2].parse().expect("Failed to parse ore costs for clay robot"),
+                ore: cost(2, "ore costs for clay robot")?,
                 clay: 0,
                 obsidian: 0,
             },
             costs_obsidian_robot: Costs {
-                ore: captures[3].parse().expect("Failed to parse ore costs for obsidian robot"),
-                clay: captures[4].parse().expect("Failed to parse clay costs for obsidian robot"),
+                ore: cost(3, "ore costs for obsidian robot")?,
+                clay: cost(4, "clay costs for obsidian robot")?,
                 obsidian: 0,
             },
             costs_geode_robot: Costs {
-                ore: captures[5].parse().expect("Failed to parse ore costs for geode robot"),
+                ore: cost(5, "ore costs for geode robot")?,
                 clay: 0,
-                obsidian: captures[6].parse().expect("Failed to parse obsidian costs for geode robot"),
+                obsidian: cost(6, "obsidian costs for geode robot")?,
             },
-        };
+        });
     }
 
     fn simulate(&self, mut minutes_remaining: i32, robots_available: Robots, ore_available: i32, clay_available: i32, obsidian_available: i32, geodes_collected: i32, max_geode_production_so_far: i32) -> i32 {
@@ -1,13 +1,40 @@
 use std::cmp::{max, min};
-use std::fs::File;
-use std::io::{BufReader, Lines};
 use std::ptr::null_mut;
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
-    let numbers: Vec<i32> = lines.map(|line| {
-        line.expect("Failed to read line").parse().expect("Failed to parse number")
-    }).collect();
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Part, Solution};
 
+pub struct Day20;
+
+impl Solution for Day20 {
+    const TITLE: &'static str = "Grove Positioning System";
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Parsed = Vec<i32>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let numbers: Vec<i32> = lines.enumerate().map(|(i, line)| {
+            let line = line?;
+
+            error::parse(&line, "number").at_line(i + 1, &line)
+        }).collect::<PuzzleResult<Vec<i32>>>()?;
+
+        if numbers.is_empty() {
+            return Err(PuzzleError::new("There are no numbers to mix"));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        mix(numbers);
+
+        Ok(None)
+    }
+}
+
+fn mix(numbers: &Vec<i32>) {
     let mut node_store = Vec::<NumberNode>::with_capacity(numbers.len()-1);
 
     let mut head = &NumberNode::new(numbers[0]);
@@ -22,11 +49,6 @@ pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
     //     head = n;
     // }
 
-    if !test {
-        return;
-    }
-
-
 }
 
 #[derive(Debug, Copy, Clone)]
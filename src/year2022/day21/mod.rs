@@ -1,28 +1,66 @@
 use std::cmp::{max, min};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Lines};
 
 use regex::Regex;
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
-    let mut monkeys_list = Vec::<String>::new();
-    let mut monkeys_map = HashMap::<String, Monkey>::new();
+use crate::cancel;
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{Answer, Input, Part, Solution};
 
-    for line in lines {
-        let (name, monkey) = Monkey::from_text(&line.expect("Failed to read line"));
+pub struct Day21;
 
-        monkeys_list.push(name.clone());
-        monkeys_map.insert(name, monkey);
+impl Solution for Day21 {
+    const TITLE: &'static str = "Monkey Math";
+    const PARTS: &'static [Part] = &[Part::Two];
+
+    // The monkeys' names in the order of the input, and the monkeys by name
+    type Parsed = (Vec<String>, HashMap<String, Monkey>);
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let mut monkeys_list = Vec::<String>::new();
+        let mut monkeys_map = HashMap::<String, Monkey>::new();
+        let mut texts = Vec::<String>::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+            let (name, monkey) = Monkey::from_text(&line).at_line(i + 1, &line)?;
+
+            monkeys_list.push(name.clone());
+            monkeys_map.insert(name, monkey);
+            texts.push(line);
+        }
+
+        // Make sure every monkey listens to monkeys that actually exist
+        for (i, name) in monkeys_list.iter().enumerate() {
+            if let Monkey::Calculator(in_a, in_b, _) = &monkeys_map[name] {
+                if let Some(unknown) = [in_a, in_b].into_iter().find(|m| !monkeys_map.contains_key(*m)) {
+                    return Err(PuzzleError::new(format!("There is no monkey '{}'", unknown)).at_line(i + 1, &texts[i]));
+                }
+            }
+        }
+
+        for name in ["root", "humn"] {
+            if !monkeys_map.contains_key(name) {
+                return Err(PuzzleError::new(format!("There is no monkey '{}'", name)));
+            }
+        }
+
+        return Ok((monkeys_list, monkeys_map));
     }
 
-    // let mut input_humn = 3093175999999; 3093175982597 3093175982597 3093175982595
+    fn part2((monkeys_list, monkeys_map): &Self::Parsed, params: &Params) -> PuzzleResult<Option<Answer>> {
+        // let mut input_humn = 3093175999999; 3093175982597 3093175982597 3093175982595
 
-    if test {
-        return;
+        // Where to start searching, the search takes forever if we start too far away
+        let humn_guess = params.get_or::<i128>("humn_guess", 0)?;
+
+        return Ok(Some(find_number_to_yell(monkeys_list, monkeys_map, humn_guess)?.into()));
     }
+}
 
-    let mut input_humn = 3093175982500;
+fn find_number_to_yell(monkeys_list: &Vec<String>, monkeys_map: &HashMap<String, Monkey>, mut input_humn: i128) -> PuzzleResult<i128> {
+    let token = cancel::current();
 
     let mut good_order = Vec::<String>::with_capacity(monkeys_list.len());
 
@@ -35,6 +73,8 @@ pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
 
 
     loop {
+        token.check()?;
+
         let mut mm = monkeys_map.clone();
 
         mm.insert("humn".to_string(), Monkey::Yeller(input_humn));
@@ -56,12 +96,10 @@ pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
                             (Monkey::Yeller { 0: in_a_num }, Monkey::Yeller { 0: in_b_num }) => {
                                 if name == "root" {
                                     if in_a_num.eq(in_b_num) {
-                                        println!("Found valid input for 'humn': {}", input_humn);
-
-                                        return;
+                                        return Ok(input_humn);
                                     }
 
-                                    println!("{} != {}, {}", in_a_num, in_b_num, in_a_num - in_b_num);
+                                    trace!("humn = {}: {} != {}, {}", input_humn, in_a_num, in_b_num, in_a_num - in_b_num);
 
                                     last_delta = delta;
                                     delta = in_a_num - in_b_num;
@@ -92,7 +130,7 @@ pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
             // // Check whether we are done
             // match monkeys_map.get("root").expect("Monkey 'root' not in map!?!") {
             //     Monkey::Yeller(number) => {
-            //         println!("Part 1: Root monkey will yell: {}", number);
+            //         outln!("Part 1: Root monkey will yell: {}", number);
             //
             //         break;
             //     }
@@ -109,7 +147,7 @@ pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
             step /= 10;
             step = -step;
 
-            println!("New step size: {}", step);
+            debug!("New step size: {}", step);
         }
 
         input_humn += step;
@@ -117,7 +155,7 @@ pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
 }
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-enum Operation {
+pub enum Operation {
     Add,
     Sub,
     Multiply,
@@ -125,30 +163,30 @@ enum Operation {
 }
 
 impl Operation {
-    fn from_text(s: &str) -> Operation {
+    fn from_text(s: &str) -> PuzzleResult<Operation> {
         return match s {
-            "+" => { Operation::Add }
-            "-" => { Operation::Sub }
-            "*" => { Operation::Multiply }
-            "/" => { Operation::Divide }
+            "+" => { Ok(Operation::Add) }
+            "-" => { Ok(Operation::Sub) }
+            "*" => { Ok(Operation::Multiply) }
+            "/" => { Ok(Operation::Divide) }
             _ => {
-                panic!("Invalid operation")
+                Err(PuzzleError::new(format!("Invalid operation '{}'", s)))
             }
         };
     }
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
-enum Monkey {
+pub enum Monkey {
     Calculator(String, String, Operation),
     Yeller(i128),
 }
 
 impl Monkey {
-    fn from_text(line: &String) -> (String, Monkey) {
+    fn from_text(line: &String) -> PuzzleResult<(String, Monkey)> {
         let re: Regex = Regex::new(r"^(\w{4}): ((\w{4}) (.) (\w{4})|(\d*))$").expect("Failed to create RegEx");
 
-        let captures = re.captures(line).unwrap();
+        let captures = re.captures(line).ok_or_else(|| PuzzleError::new("Expected a monkey like 'root: pppw + sjmn' or 'dbpl: 5'"))?;
 
         let name = captures.get(1).expect("Failed to match name").as_str().to_string();
 
@@ -158,11 +196,11 @@ impl Monkey {
             let op = captures.get(4).expect("Failed to match operator");
             let in_b = captures.get(5).expect("Failed to match input B");
 
-            return (name, Monkey::Calculator(in_a.as_str().to_string(), in_b.as_str().to_string(), Operation::from_text(op.as_str())));
+            return Ok((name, Monkey::Calculator(in_a.as_str().to_string(), in_b.as_str().to_string(), Operation::from_text(op.as_str())?)));
         }
 
         let num = captures.get(2).expect("Failed to match number");
 
-        return (name, Monkey::Yeller(num.as_str().parse().expect("Failed to parse number")));
+        return Ok((name, Monkey::Yeller(error::parse(num.as_str(), "number")?)));
     }
 }
\ No newline at end of file
@@ -0,0 +1,354 @@
+use std::ops::Not;
+
+use crate::error::{self, AtLine, PuzzleError, PuzzleResult};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::point::{Direction, Point};
+use crate::puzzle::{self, Answer, Input, Part, Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const TITLE: &'static str = "Monkey Map";
+    const PARTS: &'static [Part] = &[];
+
+    type Parsed = (Map, Vec<Instruction>);
+
+    fn parse(lines: &mut Input, params: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
+
+        // The map and the instructions are separated by an empty line
+        if all_lines.len() < 3 || !all_lines[all_lines.len() - 2].is_empty() {
+            return Err(PuzzleError::new("Expected the map, an empty line and the instructions"));
+        }
+
+        // The length of a cube's edge
+        let block_size = params.get::<usize>("block_size")?;
+
+        if block_size == 0 {
+            return Err(PuzzleError::new("The block size must not be 0"));
+        }
+
+        let map = Map::from_text(&all_lines[0..all_lines.len() - 2], block_size)?;
+        let instructions = parse_instructions(&all_lines[all_lines.len() - 1]).at_line(all_lines.len(), &all_lines[all_lines.len() - 1])?;
+
+        Ok((map, instructions))
+    }
+
+    // Neither part is solved yet: following the instructions in cube mode does not end up at the
+    // example's final position, so the code it yields is only logged
+    fn part2((map, instructions): &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        if !map.has_supported_layout() {
+            return Ok(None);
+        }
+
+        let mut map = map.clone();
+
+        trace!("Map:\n{}", map.to_string());
+
+        let code = map.follow_instructions(instructions, true);
+
+        trace!("Path followed:\n{}", map.to_string());
+        debug!("Code for the final position in cube mode: {}", code);
+
+        Ok(None)
+    }
+}
+
+fn parse_instructions(s: &String) -> PuzzleResult<Vec<Instruction>> {
+    let mut vec = Vec::<Instruction>::new();
+
+    let mut buf = String::new();
+
+    let handle_steps_token = |buf: &mut String, vec: &mut Vec<Instruction>| -> PuzzleResult<()> {
+        let steps = error::parse(buf, "steps")?;
+
+        vec.push(Instruction::MOVE(steps));
+
+        buf.clear();
+
+        Ok(())
+    };
+
+    for c in s.chars() {
+        match c {
+            'L' => {
+                handle_steps_token(&mut buf, &mut vec)?;
+
+                vec.push(Instruction::TURN(TurnDirection::COUNTER_CLOCKWISE))
+            }
+            'R' => {
+                handle_steps_token(&mut buf, &mut vec)?;
+
+                vec.push(Instruction::TURN(TurnDirection::CLOCKWISE))
+            }
+            _ => {
+                buf.push(c);
+            }
+        }
+    }
+
+    if buf.is_empty().not() {
+        handle_steps_token(&mut buf, &mut vec)?;
+    }
+
+    return Ok(vec);
+}
+
+type Tile = char;
+
+const OUT: Tile = ' ';
+const OPEN: Tile = '.';
+const WALL: Tile = '#';
+const VISITED_DOWN: Tile = 'v';
+const VISITED_UP: Tile = '^';
+const VISITED_LEFT: Tile = '<';
+const VISITED_RIGHT: Tile = '>';
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<Tile>,
+    cur_pos: Point,
+    cur_dir: Direction,
+    block_size: usize,
+}
+
+impl Map {
+    fn from_text(lines: &[String], block_size: usize) -> PuzzleResult<Map> {
+        // Trailing whitespace is omitted in the input, so shorter rows are filled up with emptiness
+        let mut grid = Grid::parse_padded(lines, OUT, |c| match c {
+            OUT | OPEN | WALL => Ok(c),
+            _ => Err(PuzzleError::new(format!("Invalid tile '{}'", c))),
+        })?;
+
+        // Map indices are 1-based
+        grid.set_origin(1, 1);
+
+        // Find the starting position
+        let cur_pos = Point {
+            x: lines[0].find(OPEN).ok_or_else(|| PuzzleError::new("Could not find OPEN field in first row").at_line(1, &lines[0]))? as i64 + 1,
+            y: 1,
+        };
+
+        return Ok(Map {
+            grid,
+            cur_pos,
+            cur_dir: Direction::Right,
+            block_size,
+        });
+    }
+
+    // Folding the map into a cube only works for the layout of the example so far, i.e. four
+    // blocks wide and three blocks high (see `determine_side`)
+    fn has_supported_layout(&self) -> bool {
+        self.grid.width() == 4 * self.block_size && self.grid.height() == 3 * self.block_size
+    }
+
+    fn at(&self, coord: &Point) -> Tile {
+        return self.grid[*coord];
+    }
+
+    fn set(&mut self, coord: &Point, tile: Tile) {
+        self.grid[*coord] = tile;
+    }
+
+    fn is_off_map(&self, coord: &Point) -> bool {
+        self.grid.get(coord.x, coord.y).map_or(true, |&tile| tile == OUT)
+    }
+
+    fn follow_instructions(&mut self, instructions: &[Instruction], cube_mode: bool) -> usize {
+        for instruction in instructions {
+            match instruction {
+                Instruction::MOVE(steps) => {
+                    self.follow_instruction(*steps, cube_mode);
+                }
+                Instruction::TURN(direction) => {
+                    self.cur_dir = turn(self.cur_dir, direction)
+                }
+            }
+        }
+
+        let code = 1000 * self.cur_pos.y + 4 * self.cur_pos.x + direction_code(self.cur_dir);
+
+        return code as usize;
+    }
+
+    fn follow_instruction(&mut self, steps: usize, cube_mode: bool) {
+        for i in 0..steps {
+            let mut next = self.cur_pos.step(self.cur_dir);
+
+            // Wrap around in case we would leave the board
+            if self.is_off_map(&next) {
+                let (new_next, new_direction) = self.wrap_around(cube_mode);
+
+                if self.at(&new_next) == WALL {
+                    return;
+                }
+
+                next = new_next;
+
+                self.cur_dir = new_direction;
+            }
+
+            let next_tile = self.at(&next);
+
+            // Stop in case we hit a wall
+            if next_tile == WALL {
+                return;
+            }
+
+            let cur_dir_tile = visited_tile(self.cur_dir);
+            let cur_pos = self.cur_pos;
+
+            self.set(&cur_pos, cur_dir_tile);
+
+            self.cur_pos = next;
+        }
+    }
+
+    fn wrap_around(&self, cube_mode: bool) -> (Point, Direction) {
+        if !cube_mode {
+            return (self.find_opposite_edge(), self.cur_dir);
+        }
+
+        let cur_side = self.determine_side(&self.cur_pos);
+
+        let size = self.block_size as i64;
+
+        let x_in_block = (self.cur_pos.x - 1) % size;
+        let y_in_block = (self.cur_pos.y - 1) % size;
+
+        return match (cur_side, self.cur_dir) {
+            (CubeSide::A, Direction::Up) => { (Point::new(size - 1 - x_in_block + 1, size + 1), Direction::Down) }
+            (CubeSide::A, Direction::Left) => { (Point::new(size + y_in_block + 1, size + 1), Direction::Down) }
+            (CubeSide::A, Direction::Right) => { (Point::new(4 * size + 1, 3 * size - 1 - y_in_block + 1), Direction::Left) }
+            // A -> D is not special
+            (CubeSide::E, Direction::Down) => { (Point::new(size - 1 - x_in_block + 1, 2 * size + 1), Direction::Up) }
+            (CubeSide::E, Direction::Left) => { (Point::new(2 * size - 1 - y_in_block + 1, 2 * size + 1), Direction::Up) }
+            // E -> D is not special
+            // E -> F is not special
+            (CubeSide::F, Direction::Up) => { (Point::new(3 * size + 1, 2 * size - 1 - x_in_block + 1), Direction::Left) }
+            (CubeSide::F, Direction::Right) => { (Point::new(3 * size + 1, size - 1 - y_in_block + 1), Direction::Left) }
+            (CubeSide::F, Direction::Down) => { (Point::new(1, 2 * size - 1 - x_in_block + 1), Direction::Right) }
+
+            (CubeSide::B, Direction::Up) => { (Point::new(3 * size - 1 - x_in_block + 1, 1), Direction::Down) }
+            (CubeSide::B, Direction::Down) => { (Point::new(3 * size - 1 - x_in_block + 1, 3 * size + 1), Direction::Up) }
+            (CubeSide::B, Direction::Left) => { (Point::new(3 * size - 1 + y_in_block + 1, 3 * size + 1), Direction::Up) }
+
+            (CubeSide::C, Direction::Up) => { (Point::new(2 * size + 1, x_in_block + 1), Direction::Right) }
+            (CubeSide::C, Direction::Down) => { (Point::new(2 * size + 1, 3 * size - 1 - x_in_block + 1), Direction::Right) }
+
+            (CubeSide::D, Direction::Right) => { (Point::new(4 * size - 1 - y_in_block + 1, 2 * size + 1), Direction::Down) }
+
+            (a, b) => {panic!("Unexpected Wrap aroung from {:?} to {:?}", a, b)}
+        };
+    }
+
+    fn determine_side(&self, coord: &Point) -> CubeSide {
+        // --A-
+        // BCD-
+        // --EF
+        let block_x = (coord.x - 1) / self.block_size as i64;
+        let block_y = (coord.y - 1) / self.block_size as i64;
+
+        if block_x == 0 && block_y == 0 {
+            return CubeSide::A;
+        }
+
+        if block_y == 1 {
+            if block_x == 0 {
+                return CubeSide::B;
+            }
+
+            if block_x == 1 {
+                return CubeSide::C;
+            }
+
+            if block_x == 2 {
+                return CubeSide::D;
+            }
+        }
+
+        if block_y == 2 {
+            if block_x == 2 {
+                return CubeSide::E;
+            }
+
+            if block_x == 3 {
+                return CubeSide::F;
+            }
+        }
+
+        panic!("Could not determine cube side, coords probably invalid")
+    }
+
+    fn find_opposite_edge(&self) -> Point {
+        let rev_direction = self.cur_dir.reverse();
+
+        let mut cur_pos = self.cur_pos;
+
+        loop {
+            let next = cur_pos.step(rev_direction);
+
+            if self.is_off_map(&next) {
+                return cur_pos;
+            }
+
+            cur_pos = next;
+        }
+    }
+
+    fn to_string(&self) -> String {
+        return self.grid.render(|&tile| tile);
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum Instruction {
+    MOVE(usize),
+    TURN(TurnDirection),
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum TurnDirection {
+    CLOCKWISE,
+    COUNTER_CLOCKWISE,
+}
+
+fn visited_tile(direction: Direction) -> Tile {
+    match direction {
+        Direction::Right => { VISITED_RIGHT }
+        Direction::Left => { VISITED_LEFT }
+        Direction::Up => { VISITED_UP }
+        Direction::Down => { VISITED_DOWN }
+    }
+}
+
+fn turn(direction: Direction, turn_dir: &TurnDirection) -> Direction {
+    match turn_dir {
+        TurnDirection::CLOCKWISE => direction.turn_right(),
+        TurnDirection::COUNTER_CLOCKWISE => direction.turn_left(),
+    }
+}
+
+// The facing's part of the password
+fn direction_code(direction: Direction) -> i64 {
+    match direction {
+        Direction::Right => { 0 }
+        Direction::Left => { 2 }
+        Direction::Up => { 3 }
+        Direction::Down => { 1 }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum CubeSide {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+}
+
+
@@ -0,0 +1,198 @@
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::point::{Direction, Point};
+use crate::puzzle::{Answer, Input, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const TITLE: &'static str = "Unstable Diffusion";
+
+    type Parsed = HashSet<Point>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        Ok(HashSet::<Point>::from_iter(parse_field(lines)?))
+    }
+
+    fn part1(elves: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut part1 = elves.clone();
+
+        for i in 0..10 {
+            simulate_round(&mut part1, i);
+
+            trace!("After round {}:\n{}", i + 1, render(&part1));
+        }
+
+
+        let area = compute_bounding_box_area(elves);
+
+        Ok(Some((area - elves.len() as i64).into()))
+    }
+
+    fn part2(elves: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut elves = elves.clone();
+        let mut round = 0;
+
+        loop {
+            if !simulate_round(&mut elves, round) {
+                break;
+            }
+
+            round += 1;
+        }
+
+        Ok(Some((round + 1).into()))
+    }
+}
+
+fn render(coords_set: &HashSet<Point>) -> String {
+    let mut rendered = String::new();
+    let mut min_x = 0;
+    let mut max_x = 0;
+    let mut min_y = 0;
+    let mut max_y = 0;
+
+    for coord in coords_set {
+        min_x = min(min_x, coord.x);
+        max_x = max(max_x, coord.x);
+        min_y = min(min_y, coord.y);
+        max_y = max(max_y, coord.y);
+    }
+
+    let width = (max_x - min_x) as usize + 1;
+    let height = (max_y - min_y) as usize + 1;
+
+    for y in 0..height {
+        for x in 0..width {
+            match coords_set.contains(&Point::new(x as i64 + min_x, y as i64 + min_y)) {
+                true => rendered.push('#'),
+                false => rendered.push('.'),
+            }
+        }
+
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+
+fn compute_bounding_box_area(coords_set: &HashSet<Point>) -> i64 {
+    let mut min_x = 0;
+    let mut max_x = 0;
+    let mut min_y = 0;
+    let mut max_y = 0;
+
+    for coord in coords_set {
+        min_x = min(min_x, coord.x);
+        max_x = max(max_x, coord.x);
+        min_y = min(min_y, coord.y);
+        max_y = max(max_y, coord.y);
+    }
+
+    (max_x - min_x + 1) * (max_y - min_y + 1)
+}
+
+fn simulate_round(coords_set: &mut HashSet<Point>, round: usize) -> bool {
+    let elves_that_need_to_move = coords_set.iter().filter(|elf| {
+        elf.all_neighbours().iter().any(|adjacent_coord| coords_set.contains(adjacent_coord))
+    });
+
+    let mut propositions = HashMap::<Point, (Point, bool)>::with_capacity(coords_set.len());
+
+    elves_that_need_to_move.for_each(|elf| {
+        match find_valid_move(elf, &coords_set, round) {
+            None => {}
+            Some(new_coord) => {
+                if propositions.contains_key(&new_coord) {
+                    propositions.remove(&new_coord);
+
+                    propositions.insert(new_coord, (*elf, false));
+                } else {
+                    propositions.insert(new_coord, (*elf, true));
+                }
+            }
+        }
+    });
+
+    let mut changes = 0;
+
+    for (proposed_position, (current_position, valid_proposition)) in propositions {
+        if !valid_proposition {
+            continue;
+        }
+
+        changes += 1;
+
+        coords_set.remove(&current_position);
+
+        coords_set.insert(proposed_position);
+    }
+
+    changes > 0
+}
+
+fn find_valid_move(elf_pos: &Point, coords_set: &HashSet<Point>, round: usize) -> Option<Point> {
+    let is_free = |x_offset: i64, y_offset: i64| {
+        !coords_set.contains(&(*elf_pos + Point::new(x_offset, y_offset)))
+    };
+
+    for i in 0..4 {
+        let a = ((round % 4) + i) % 4;
+
+        match a {
+            0 => {
+                if is_free(0, -1) && is_free(-1, -1) && is_free(1, -1) {
+                    trace!("{} moves up", elf_pos);
+
+                    return Some(elf_pos.step(Direction::Up));
+                }
+            }
+            1 => {
+                if is_free(0, 1) && is_free(-1, 1) && is_free(1, 1) {
+                    trace!("{} moves down", elf_pos);
+
+                    return Some(elf_pos.step(Direction::Down));
+                }
+            }
+            2 => {
+                if is_free(-1, -1) && is_free(-1, 0) && is_free(-1, 1) {
+                    trace!("{} moves left", elf_pos);
+
+                    return Some(elf_pos.step(Direction::Left));
+                }
+            }
+            3 => {
+                if is_free(1, -1) && is_free(1, 0) && is_free(1, 1) {
+                    trace!("{} moves right", elf_pos);
+
+                    return Some(elf_pos.step(Direction::Right));
+                }
+            }
+            _ => { panic!("This should never happen") }
+        }
+    }
+
+    return None;
+}
+
+fn parse_field(lines: &mut Input) -> PuzzleResult<Vec<Point>> {
+    let mut vec = Vec::<Point>::new();
+
+    for (y, line) in lines.enumerate() {
+        let line = line?;
+
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '#' => vec.push( Point::new(x as i64, y as i64)),
+                '.' => {}
+                _ => return Err(PuzzleError::new(format!("Invalid tile '{}'", c)).at_line(y + 1, &line)),
+            }
+        }
+    }
+
+    return Ok(vec);
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use crate::cycle::Cycle;
+use crate::error::{PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::point::{Direction, Point};
+use crate::puzzle::{self, Answer, Input, Solution};
+use crate::search;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const TITLE: &'static str = "Blizzard Basin";
+
+    // The blizzards are only needed for forecasting the weather, which is the same for both parts
+    type Parsed = (Map, BlizzardPrediction);
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        let all_lines = puzzle::read_all_lines(lines)?;
+
+        let (map, blizzards) = parse_map(&all_lines)?;
+
+        let weatherman = BlizzardPrediction::new(&blizzards, map.width, map.height);
+
+        for i in 0..20 {
+            trace!("Blizzards in minute {}:\n{}", i, render(&map, weatherman.predict_for_iteration(i)));
+        }
+
+        Ok((map, weatherman))
+    }
+
+    fn part1((map, weatherman): &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(find_path_there(map, weatherman)?.into()))
+    }
+
+    fn part2((map, weatherman): &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        Ok(Some(find_path_there_and_back_again(map, weatherman)?.into()))
+    }
+}
+
+fn find_path_there(map: &Map, weatherman: &BlizzardPrediction) -> PuzzleResult<usize> {
+    cross_valley(map.start, map.end, 0, map, weatherman)
+}
+
+fn find_path_there_and_back_again(map: &Map, weatherman: &BlizzardPrediction) -> PuzzleResult<usize> {
+    let there = cross_valley(map.start, map.end, 0, map, weatherman)?;
+    let back = cross_valley(map.end, map.start, there, map, weatherman)?;
+    let there_again = cross_valley(map.start, map.end, back, map, weatherman)?;
+
+    debug!("Part 2: {} + {} + {}", there, back - there, there_again - back);
+
+    Ok(there_again)
+}
+
+// The earliest minute to get from one side of the valley to the other, setting off in the given
+// minute
+fn cross_valley(from: Point, to: Point, minute: usize, map: &Map, weatherman: &BlizzardPrediction) -> PuzzleResult<usize> {
+    // The weather repeats, so being somewhere in a minute is the same as being there any number of
+    // repetitions later, which keeps the number of states finite
+    let path = search::astar(
+        (from, weatherman.same_as_iteration(minute)),
+        |&(pos, iteration)| {
+            let next_iteration = weatherman.same_as_iteration(iteration + 1);
+            let prediction = weatherman.predict_for_iteration(next_iteration);
+
+            // Moving or waiting
+            Direction::ALL.map(|direction| pos.step(direction)).into_iter().chain([pos])
+                .filter(move |&next| next == from || next == to || !map.is_off(next))
+                .filter(move |next| !prediction.contains(next))
+                .map(move |next| ((next, next_iteration), 1))
+        },
+        |&(pos, _)| pos.manhattan_distance(to) as usize,
+        |&(pos, _)| pos == to,
+    ).ok_or_else(|| PuzzleError::new(format!("There is no way from {} to {} through the blizzards", from, to)))?;
+
+    trace!("Crossed from {} to {}: {}", from, to, path.states.iter().map(|(pos, _)| pos.to_string()).collect::<Vec<String>>().join(" "));
+
+    Ok(minute + path.cost)
+}
+
+fn render(map: &Map, forecast: &HashSet<Point>) -> String {
+    let mut rendered = String::new();
+
+    for y in 0..map.height {
+        for x in 0..map.width {
+            match forecast.contains(&Point::new(x as i64 + 1, y as i64 + 1)) {
+                true => rendered.push('#'),
+                false => rendered.push('.'),
+            }
+        }
+
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+fn parse_map(lines: &[String]) -> PuzzleResult<(Map, Vec<Blizzard>)> {
+    let mut blizzards = Vec::<Blizzard>::new();
+
+    // The valley has to be surrounded by walls, at least one row and column wide
+    if lines.len() < 3 || lines[0].len() < 3 {
+        return Err(PuzzleError::new("The valley is too small"));
+    }
+
+    for (y, line) in lines.iter().enumerate() {
+        if line.len() != lines[0].len() {
+            return Err(PuzzleError::new(format!("Expected {} tiles per row", lines[0].len())).at_line(y + 1, line));
+        }
+
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '>' => { blizzards.push(Blizzard::new(Point::new(x as i64, y as i64), Direction::Right)) }
+                '<' => { blizzards.push(Blizzard::new(Point::new(x as i64, y as i64), Direction::Left)) }
+                '^' => { blizzards.push(Blizzard::new(Point::new(x as i64, y as i64), Direction::Up)) }
+                'v' => { blizzards.push(Blizzard::new(Point::new(x as i64, y as i64), Direction::Down)) }
+                '#' | '.' => {}
+                _ => { return Err(PuzzleError::new(format!("Invalid tile '{}'", c)).at_line(y + 1, line)) }
+            }
+        }
+    }
+
+    let height = lines.len() - 2;
+    let width = lines[0].len() - 2;
+    let start = Point::new(1, 0);
+    let end = Point::new(width as i64, height as i64 + 1);
+    let map = Map { width, height, start, end };
+
+    return Ok((map, blizzards));
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    start: Point,
+    end: Point,
+}
+
+impl Map {
+    fn is_off(&self, pos: Point) -> bool {
+        pos.x < 1 || pos.x > self.width as i64 || pos.y < 1 || pos.y > self.height as i64
+    }
+}
+
+struct Blizzard {
+    pos: Point,
+    dir: Direction,
+}
+
+impl Blizzard {
+    pub fn new(pos: Point, dir: Direction) -> Self {
+        Self { pos, dir }
+    }
+}
+
+pub struct BlizzardPrediction {
+    forecasts: Cycle<HashSet<Point>>,
+}
+
+impl BlizzardPrediction {
+    fn new(blizzards: &[Blizzard], width: usize, height: usize) -> Self {
+        let mut positions: Vec<Point> = blizzards.iter().map(|blizzard| blizzard.pos).collect();
+
+        // The weather repeats once all blizzards are back where they started (at the latest after
+        // width * height minutes)
+        let forecasts = Cycle::detect(|iteration| {
+            if iteration > 0 {
+                for (pos, blizzard) in positions.iter_mut().zip(blizzards) {
+                    // Blizzards reaching a wall reappear at the opposite one
+                    let moved = *pos + blizzard.dir.offset();
+
+                    *pos = Point::new((moved.x - 1).rem_euclid(width as i64) + 1, (moved.y - 1).rem_euclid(height as i64) + 1);
+                }
+            }
+
+            (positions.clone(), HashSet::<Point>::from_iter(positions.iter().copied()))
+        });
+
+        Self { forecasts }
+    }
+
+    fn predict_for_iteration(&self, n: usize) -> &HashSet<Point> {
+        self.forecasts.metric_at(n)
+    }
+
+    // The iteration with the same forecast as iteration n, out of all the different forecasts
+    fn same_as_iteration(&self, n: usize) -> usize {
+        self.forecasts.equivalent_step(n)
+    }
+}
@@ -1,20 +1,37 @@
 use std::cmp::{max, min};
-use std::fs::File;
-use std::io::{BufReader, Lines};
 
-pub fn solve(lines: &mut Lines<BufReader<File>>, test: bool) {
-    let all_lines: Vec<String> = lines.map(|line| line.expect("Failed to read line")).collect();
-    let mut sum = 0;
+use crate::error::{AtLine, PuzzleError, PuzzleResult};
+use crate::params::Params;
+use crate::puzzle::{self, Answer, Input, Part, Solution};
 
-    for line in all_lines {
-        sum += from_snafu(&line);
-        // println!("{} ==> {} ==> {}", line, from_snafu(&line), to_snafu(from_snafu(&line)));
+pub struct Day25;
+
+impl Solution for Day25 {
+    const TITLE: &'static str = "Full of Hot Air";
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Parsed = Vec<String>;
+
+    fn parse(lines: &mut Input, _: &Params) -> PuzzleResult<Self::Parsed> {
+        puzzle::read_all_lines(lines)
     }
 
-    println!("Part 1: Sum {}, SNAFU: {}", sum, to_snafu(sum));
+    fn part1(all_lines: &Self::Parsed, _: &Params) -> PuzzleResult<Option<Answer>> {
+        let mut sum = 0;
+
+        for (i, line) in all_lines.iter().enumerate() {
+            let n = from_snafu(line).at_line(i + 1, line)?;
+
+            trace!("{} ==> {} ==> {}", line, n, to_snafu(n));
+
+            sum += n;
+        }
+
+        Ok(Some(to_snafu(sum).into()))
+    }
 }
 
-fn from_snafu(snafu: &String) -> i64 {
+fn from_snafu(snafu: &String) -> PuzzleResult<i64> {
     let mut sum: i64 = 0;
 
     for (i, c) in snafu.chars().rev().enumerate() {
@@ -24,11 +41,11 @@ fn from_snafu(snafu: &String) -> i64 {
             '2' => { 2 }
             '-' => { -1 }
             '=' => { -2 }
-            _ => { panic!("Invalid character in SNAFU") }
+            _ => { return Err(PuzzleError::new(format!("Invalid character '{}' in SNAFU", c))) }
         };
     }
 
-    return sum;
+    return Ok(sum);
 }
 
 fn to_snafu(mut num: i64) -> String {